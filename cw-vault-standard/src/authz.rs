@@ -0,0 +1,221 @@
+use cosmwasm_std::{CosmosMsg, StdError, StdResult, Timestamp, Uint128, WasmMsg};
+use osmosis_std::shim;
+use osmosis_std::types::cosmos::authz::v1beta1::{Grant, MsgExec, MsgGrant};
+use osmosis_std::types::cosmwasm::wasm::v1::{
+    AcceptedMessageKeysFilter, ContractExecutionAuthorization, ContractGrant, MaxCallsLimit,
+    MsgExecuteContract,
+};
+
+use crate::{ExecuteExtension, VaultContract, VaultStandardError};
+
+/// The top level JSON key of the `Deposit` execute message.
+pub const DEPOSIT_MSG_KEY: &str = "deposit";
+
+/// The top level JSON key of the `Redeem` execute message.
+pub const REDEEM_MSG_KEY: &str = "redeem";
+
+/// The top level JSON key of the `VaultExtension` execute message. Since authz
+/// filters only match top level keys, granting this key allows execution of
+/// all extension messages, e.g. the lockup `Unlock` message, but also any
+/// admin or strategy message the granter is allowed to send.
+pub const VAULT_EXTENSION_MSG_KEY: &str = "vault_extension";
+
+/// Wraps the given messages in an authz `MsgExec`, to be executed by `grantee`
+/// on behalf of `granter`. All messages must be `WasmMsg::Execute` messages,
+/// which are converted to `MsgExecuteContract` messages with `granter` as the
+/// sender.
+pub fn wrap_in_msg_exec<C>(
+    granter: impl Into<String>,
+    grantee: impl Into<String>,
+    msgs: Vec<CosmosMsg<C>>,
+) -> StdResult<CosmosMsg<C>> {
+    let granter = granter.into();
+    let msgs = msgs
+        .into_iter()
+        .map(|msg| match msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) => Ok(MsgExecuteContract {
+                sender: granter.clone(),
+                contract: contract_addr,
+                msg: msg.to_vec(),
+                funds: funds.into_iter().map(Into::into).collect(),
+            }
+            .to_any()),
+            _ => Err(StdError::generic_err(
+                "Only WasmMsg::Execute messages can be wrapped in MsgExec",
+            )),
+        })
+        .collect::<StdResult<Vec<shim::Any>>>()?;
+
+    Ok(MsgExec {
+        grantee: grantee.into(),
+        msgs,
+    }
+    .into())
+}
+
+impl<E, Q> VaultContract<E, Q> {
+    /// Returns a CosmosMsg for `grantee` to deposit base tokens into the vault
+    /// on behalf of `granter`. The base tokens are sent from the balance of
+    /// `granter`, who must have granted `grantee` authorization to execute the
+    /// `Deposit` message on the vault, e.g. with [`Self::grant_msg`].
+    pub fn deposit_authz<C>(
+        &self,
+        granter: impl Into<String>,
+        grantee: impl Into<String>,
+        amount: impl Into<Uint128>,
+        base_denom: &str,
+        recipient: Option<String>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        Ok(wrap_in_msg_exec(
+            granter,
+            grantee,
+            vec![self.deposit(amount, base_denom, recipient)?],
+        )?)
+    }
+
+    /// Returns a CosmosMsg for `grantee` to redeem vault tokens from the vault
+    /// on behalf of `granter`. The vault tokens are sent from the balance of
+    /// `granter`, who must have granted `grantee` authorization to execute the
+    /// `Redeem` message on the vault, e.g. with [`Self::grant_msg`].
+    pub fn redeem_authz<C>(
+        &self,
+        granter: impl Into<String>,
+        grantee: impl Into<String>,
+        amount: impl Into<Uint128>,
+        vault_token_denom: &str,
+        recipient: Option<String>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        Ok(wrap_in_msg_exec(
+            granter,
+            grantee,
+            vec![self.redeem(amount, vault_token_denom, recipient)?],
+        )?)
+    }
+
+    /// Returns a CosmosMsg for `grantee` to start unlocking vault tokens on
+    /// behalf of `granter`. The vault tokens are sent from the balance of
+    /// `granter`, who must have granted `grantee` authorization to execute the
+    /// `VaultExtension` message on the vault, e.g. with [`Self::grant_msg`].
+    /// Note that such a grant covers all extension messages, see
+    /// [`Self::operator_grant_msg`].
+    #[cfg(feature = "lockup")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "lockup")))]
+    pub fn unlock_authz<C>(
+        &self,
+        granter: impl Into<String>,
+        grantee: impl Into<String>,
+        amount: impl Into<Uint128>,
+        vault_token_denom: &str,
+    ) -> Result<CosmosMsg<C>, VaultStandardError> {
+        Ok(wrap_in_msg_exec(
+            granter,
+            grantee,
+            vec![self.unlock(amount, vault_token_denom)?],
+        )?)
+    }
+
+    /// Returns a `MsgGrant` CosmosMsg, to be sent by `granter`, granting
+    /// `grantee` authorization to execute messages on this vault only. Only
+    /// messages with one of the given top level JSON `msg_keys` are allowed
+    /// (see e.g. [`DEPOSIT_MSG_KEY`]), at most `max_calls` times. The grant
+    /// expires at `expiration`, if set.
+    pub fn grant_msg<C>(
+        &self,
+        granter: impl Into<String>,
+        grantee: impl Into<String>,
+        msg_keys: &[&str],
+        max_calls: u64,
+        expiration: Option<Timestamp>,
+    ) -> CosmosMsg<C> {
+        let authorization = ContractExecutionAuthorization {
+            grants: vec![ContractGrant {
+                contract: self.addr.to_string(),
+                limit: Some(
+                    MaxCallsLimit {
+                        remaining: max_calls,
+                    }
+                    .to_any(),
+                ),
+                filter: Some(
+                    AcceptedMessageKeysFilter {
+                        keys: msg_keys.iter().map(|key| key.to_string()).collect(),
+                    }
+                    .to_any(),
+                ),
+            }],
+        };
+
+        MsgGrant {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            grant: Some(Grant {
+                authorization: Some(authorization.to_any()),
+                expiration: expiration.map(|t| shim::Timestamp {
+                    seconds: t.seconds() as i64,
+                    nanos: t.subsec_nanos() as i32,
+                }),
+            }),
+        }
+        .into()
+    }
+
+    /// Returns a `MsgGrant` CosmosMsg granting `grantee` authorization to
+    /// execute `Deposit` and `Redeem` messages on this vault on behalf of
+    /// `granter`. See [`Self::grant_msg`].
+    pub fn deposit_redeem_grant_msg<C>(
+        &self,
+        granter: impl Into<String>,
+        grantee: impl Into<String>,
+        max_calls: u64,
+        expiration: Option<Timestamp>,
+    ) -> CosmosMsg<C> {
+        self.grant_msg(
+            granter,
+            grantee,
+            &[DEPOSIT_MSG_KEY, REDEEM_MSG_KEY],
+            max_calls,
+            expiration,
+        )
+    }
+
+    /// Returns a `MsgGrant` CosmosMsg granting `grantee` authorization to
+    /// execute `Deposit`, `Redeem` and extension messages, such as the lockup
+    /// `Unlock` message, on this vault on behalf of `granter`. See
+    /// [`Self::grant_msg`].
+    ///
+    /// # Security
+    ///
+    /// Authz filters only match top level JSON keys, so this grant can not be
+    /// narrowed to individual extensions. It allows `grantee` to execute
+    /// **every** extension message of the vault as `granter`, including admin,
+    /// strategy and other privileged messages if `granter` holds such a role
+    /// on the vault. Only grant it to fully trusted operators, and never from
+    /// an address with privileged roles on the vault. Use
+    /// [`Self::deposit_redeem_grant_msg`] if extension messages are not
+    /// needed.
+    pub fn operator_grant_msg<C>(
+        &self,
+        granter: impl Into<String>,
+        grantee: impl Into<String>,
+        max_calls: u64,
+        expiration: Option<Timestamp>,
+    ) -> CosmosMsg<C> {
+        self.grant_msg(
+            granter,
+            grantee,
+            &[DEPOSIT_MSG_KEY, REDEEM_MSG_KEY, VAULT_EXTENSION_MSG_KEY],
+            max_calls,
+            expiration,
+        )
+    }
+}
@@ -0,0 +1,152 @@
+use cosmwasm_std::{coin, to_json_binary, CosmosMsg, ReplyOn, SubMsg, Uint128, WasmMsg};
+
+use crate::helper::validate_msg;
+use crate::{
+    ExecuteExtension, QueryExtension, VaultContract, VaultStandardError, VaultStandardExecuteMsg,
+};
+
+impl<E, Q> VaultContract<E, Q> {
+    /// Returns a [`DepositBuilder`] to build a message depositing `amount`
+    /// base tokens into the vault.
+    pub fn deposit_builder(&self, amount: impl Into<Uint128>) -> DepositBuilder<'_, E, Q> {
+        DepositBuilder {
+            vault: self,
+            amount: amount.into(),
+            base_denom: None,
+            recipient: None,
+        }
+    }
+
+    /// Returns a [`RedeemBuilder`] to build a message redeeming `amount` vault
+    /// tokens from the vault.
+    pub fn redeem_builder(&self, amount: impl Into<Uint128>) -> RedeemBuilder<'_, E, Q> {
+        RedeemBuilder {
+            vault: self,
+            amount: amount.into(),
+            vault_token_denom: None,
+            recipient: None,
+        }
+    }
+}
+
+/// A builder for `Deposit` messages, created with
+/// [`VaultContract::deposit_builder`].
+pub struct DepositBuilder<'a, E, Q> {
+    vault: &'a VaultContract<E, Q>,
+    amount: Uint128,
+    base_denom: Option<String>,
+    recipient: Option<String>,
+}
+
+impl<'a, E, Q> DepositBuilder<'a, E, Q>
+where
+    E: ExecuteExtension,
+    Q: QueryExtension,
+{
+    /// Attach the deposited amount of the native base token `denom` as funds.
+    /// If not set, no funds are attached, e.g. for cw20 base tokens for which
+    /// spend has been approved.
+    pub fn base_denom(mut self, denom: impl Into<String>) -> Self {
+        self.base_denom = Some(denom.into());
+        self
+    }
+
+    /// Set the recipient of the vault tokens. If not set, the caller address
+    /// is used.
+    pub fn recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.recipient = Some(recipient.into());
+        self
+    }
+
+    /// Build the `Deposit` message as a CosmosMsg. Returns an error if the
+    /// amount is zero or the recipient is the vault itself.
+    pub fn build<C>(self) -> Result<CosmosMsg<C>, VaultStandardError> {
+        validate_msg(&self.vault.addr, self.amount, &self.recipient)?;
+        Ok(WasmMsg::Execute {
+            contract_addr: self.vault.addr.to_string(),
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Deposit {
+                amount: self.amount,
+                recipient: self.recipient,
+            })?,
+            funds: self
+                .base_denom
+                .map(|denom| vec![coin(self.amount.u128(), denom)])
+                .unwrap_or_default(),
+        }
+        .into())
+    }
+
+    /// Build the `Deposit` message as a SubMsg with the given `reply_id` and
+    /// `reply_on`.
+    pub fn build_submsg<C>(
+        self,
+        reply_id: u64,
+        reply_on: ReplyOn,
+    ) -> Result<SubMsg<C>, VaultStandardError> {
+        Ok(SubMsg {
+            reply_on,
+            ..SubMsg::reply_always(self.build()?, reply_id)
+        })
+    }
+}
+
+/// A builder for `Redeem` messages, created with
+/// [`VaultContract::redeem_builder`].
+pub struct RedeemBuilder<'a, E, Q> {
+    vault: &'a VaultContract<E, Q>,
+    amount: Uint128,
+    vault_token_denom: Option<String>,
+    recipient: Option<String>,
+}
+
+impl<'a, E, Q> RedeemBuilder<'a, E, Q>
+where
+    E: ExecuteExtension,
+    Q: QueryExtension,
+{
+    /// Attach the redeemed amount of the native vault token `denom` as funds.
+    /// If not set, no funds are attached, e.g. for cw4626 vaults that burn
+    /// the vault tokens directly.
+    pub fn vault_token_denom(mut self, denom: impl Into<String>) -> Self {
+        self.vault_token_denom = Some(denom.into());
+        self
+    }
+
+    /// Set the recipient of the withdrawn base tokens. If not set, the caller
+    /// address is used.
+    pub fn recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.recipient = Some(recipient.into());
+        self
+    }
+
+    /// Build the `Redeem` message as a CosmosMsg. Returns an error if the
+    /// amount is zero or the recipient is the vault itself.
+    pub fn build<C>(self) -> Result<CosmosMsg<C>, VaultStandardError> {
+        validate_msg(&self.vault.addr, self.amount, &self.recipient)?;
+        Ok(WasmMsg::Execute {
+            contract_addr: self.vault.addr.to_string(),
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Redeem {
+                amount: self.amount,
+                recipient: self.recipient,
+            })?,
+            funds: self
+                .vault_token_denom
+                .map(|denom| vec![coin(self.amount.u128(), denom)])
+                .unwrap_or_default(),
+        }
+        .into())
+    }
+
+    /// Build the `Redeem` message as a SubMsg with the given `reply_id` and
+    /// `reply_on`.
+    pub fn build_submsg<C>(
+        self,
+        reply_id: u64,
+        reply_on: ReplyOn,
+    ) -> Result<SubMsg<C>, VaultStandardError> {
+        Ok(SubMsg {
+            reply_on,
+            ..SubMsg::reply_always(self.build()?, reply_id)
+        })
+    }
+}
@@ -0,0 +1,59 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, Env, MessageInfo, StdResult, WasmMsg};
+use serde::Serialize;
+
+use crate::error::VaultStandardError;
+use crate::VaultStandardExecuteMsg;
+
+/// The variant of a vault's extension execute enum that carries internal
+/// callback messages. Vaults using the callback pattern should define their
+/// extension execute enum with a `Callback` variant, e.g.
+/// `enum MyExtensionMsg { Callback(MyCallbackMsg), .. }`, so that the messages
+/// built by [`CallbackBuilder`] deserialize into it.
+#[cw_serde]
+pub enum CallbackMsg<T> {
+    /// An internal message that the vault sends to itself.
+    Callback(T),
+}
+
+/// A builder for the messages a vault sends to itself to execute multi-step
+/// operations. The messages are wrapped in the `Callback` variant of the
+/// vault's extension execute enum, i.e. they are serialized as
+/// `{"vault_extension": {"callback": msg}}`. Callbacks should be guarded with
+/// [`verify_callback`].
+pub struct CallbackBuilder {
+    contract_addr: Addr,
+}
+
+impl CallbackBuilder {
+    /// Create a new CallbackBuilder instance for the contract executing in
+    /// `env`.
+    pub fn new(env: &Env) -> Self {
+        Self {
+            contract_addr: env.contract.address.clone(),
+        }
+    }
+
+    /// Returns a CosmosMsg executing the callback `msg` on the vault itself.
+    pub fn build<C, T: Serialize>(&self, msg: T) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.contract_addr.to_string(),
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                CallbackMsg::Callback(msg),
+            ))?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+/// Asserts that a callback message was sent by the vault itself, i.e. that the
+/// sender of the message is the address of the contract executing in `env`.
+pub fn verify_callback(env: &Env, info: &MessageInfo) -> Result<(), VaultStandardError> {
+    if info.sender != env.contract.address {
+        return Err(VaultStandardError::UnauthorizedCallback {
+            sender: info.sender.clone(),
+        });
+    }
+    Ok(())
+}
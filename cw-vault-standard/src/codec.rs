@@ -0,0 +1,75 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, to_json_binary, Binary, StdResult, Uint128};
+#[cfg(feature = "reply-data")]
+use cosmwasm_std::{Reply, StdError};
+#[cfg(feature = "reply-data")]
+use cw_utils::parse_execute_response_data;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// The response data a vault should set on `Deposit`: the amount of vault
+/// tokens minted. Encoded as a JSON string, e.g. `"100"`.
+#[cw_serde]
+pub struct SharesMinted(pub Uint128);
+
+/// The response data a vault should set on `Redeem`: the amount of base
+/// tokens returned. Encoded as a JSON string, e.g. `"100"`.
+#[cw_serde]
+pub struct AssetsReturned(pub Uint128);
+
+/// The response data a vault should set on the lockup `Unlock`: the id of the
+/// created unlocking position. Encoded as a JSON number, e.g. `42`.
+#[cw_serde]
+pub struct LockupId(pub u64);
+
+/// Encodes a response data payload as JSON, to be set as the data of the
+/// vault's `Response`.
+pub fn encode_response_data<T: Serialize>(payload: &T) -> StdResult<Binary> {
+    to_json_binary(payload)
+}
+
+/// Decodes a JSON response data payload, as set by the vault on its
+/// `Response`. To decode the data of a submessage reply, which is wrapped in a
+/// protobuf `MsgExecuteContractResponse`, use [`decode_reply_data`].
+pub fn decode_response_data<T: DeserializeOwned>(data: &Binary) -> StdResult<T> {
+    from_json(data)
+}
+
+/// Decodes the response data payload of the vault from the [`Reply`] of a
+/// submessage, unwrapping the protobuf `MsgExecuteContractResponse` before
+/// decoding the JSON payload.
+///
+/// # Examples
+///
+/// ```
+/// use cosmwasm_std::{Binary, Reply, SubMsgResponse, SubMsgResult, Uint128};
+/// use cw_vault_standard::codec::{decode_reply_data, SharesMinted};
+///
+/// // The protobuf encoded `MsgExecuteContractResponse` with data `"42"`
+/// let data = Binary::from(vec![0x0a, 0x04, b'"', b'4', b'2', b'"']);
+/// let reply = Reply {
+///     id: 1,
+///     result: SubMsgResult::Ok(SubMsgResponse {
+///         events: vec![],
+///         data: Some(data),
+///     }),
+/// };
+/// let shares: SharesMinted = decode_reply_data(&reply).unwrap();
+/// assert_eq!(shares, SharesMinted(Uint128::new(42)));
+/// ```
+#[cfg(feature = "reply-data")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reply-data")))]
+pub fn decode_reply_data<T: DeserializeOwned>(reply: &Reply) -> StdResult<T> {
+    let data = reply
+        .result
+        .clone()
+        .into_result()
+        .map_err(StdError::generic_err)?
+        .data
+        .ok_or_else(|| StdError::generic_err("Reply contains no response data"))?;
+    let data = parse_execute_response_data(&data)
+        .map_err(|e| StdError::generic_err(e.to_string()))?
+        .data
+        .ok_or_else(|| StdError::generic_err("Vault set no response data"))?;
+    decode_response_data(&data)
+}
@@ -0,0 +1,148 @@
+use cosmwasm_std::{Addr, Coin, Decimal, StdError, Uint128};
+use thiserror::Error;
+
+/// The error type returned by the helper functions of the vault standard that
+/// vault implementations can use to validate incoming messages.
+#[derive(Error, Debug, PartialEq)]
+pub enum VaultStandardError {
+    /// A wrapped [`StdError`].
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    /// The funds sent with a message did not consist of exactly the expected
+    /// coin.
+    #[error("Invalid funds: expected exactly {expected}, received [{}]", fmt_coins(.received))]
+    InvalidFunds {
+        /// The coin that was expected to be sent.
+        expected: Coin,
+        /// The coins that were actually sent.
+        received: Vec<Coin>,
+    },
+
+    /// The funds sent with a message did not consist of a single non-zero
+    /// coin of the vault token.
+    #[error("Invalid funds: expected only vault token {vault_token}, received [{}]", fmt_coins(.received))]
+    InvalidVaultTokenFunds {
+        /// The denom of the vault token.
+        vault_token: String,
+        /// The coins that were actually sent.
+        received: Vec<Coin>,
+    },
+
+    /// The vault implements an older version of the vault standard than
+    /// required.
+    #[error("Vault implements vault standard version {version}, but at least version {min_version} is required")]
+    IncompatibleVersion {
+        /// The version of the vault standard implemented by the vault.
+        version: u16,
+        /// The minimum required version.
+        min_version: u16,
+    },
+
+    /// The vault does not implement all required extensions.
+    #[error("Vault does not implement the required extensions: {}", .missing.join(", "))]
+    MissingExtensions {
+        /// The required extensions that the vault does not implement.
+        missing: Vec<String>,
+    },
+
+    /// The total vault token supply reported by the vault differs from the
+    /// supply of the vault token in the bank module.
+    #[error("Vault reports a total vault token supply of {reported_supply}, but the bank supply is {bank_supply}")]
+    SupplyMismatch {
+        /// The supply of the vault token in the bank module.
+        bank_supply: Uint128,
+        /// The total vault token supply reported by the vault.
+        reported_supply: Uint128,
+    },
+
+    /// A callback message was sent by another address than the vault itself.
+    #[error("Callbacks can only be sent by the vault itself, received from {sender}")]
+    UnauthorizedCallback {
+        /// The sender of the callback message.
+        sender: Addr,
+    },
+
+    /// A message was constructed with a zero amount.
+    #[error("Amount must be greater than zero")]
+    ZeroAmount {},
+
+    /// A multi asset message was constructed without any assets.
+    #[error("Assets must not be empty")]
+    EmptyAssets {},
+
+    /// A multi asset message was constructed with the same denom more than
+    /// once, which the bank module rejects when attached as funds.
+    #[error("Duplicate denom in assets: {denom}")]
+    DuplicateDenom {
+        /// The denom that occurs more than once.
+        denom: String,
+    },
+
+    /// A message was constructed with the vault itself as recipient, which
+    /// would lock the tokens in the vault.
+    #[error("Recipient must not be the vault itself ({vault})")]
+    RecipientIsVault {
+        /// The address of the vault.
+        vault: Addr,
+    },
+
+    /// A position cannot be migrated between two vaults with different base
+    /// tokens.
+    #[error(
+        "Cannot migrate position from vault with base token {from} to vault with base token {to}"
+    )]
+    BaseTokenMismatch {
+        /// The base token of the vault the position is migrated from.
+        from: String,
+        /// The base token of the vault the position is migrated to.
+        to: String,
+    },
+
+    /// A flow that attaches the base token as native funds was used with a
+    /// vault whose base token is a cw20 token.
+    #[error("Base token {base_token} is not a native token")]
+    NonNativeBaseToken {
+        /// The base token of the vault.
+        base_token: String,
+    },
+
+    /// A flow that attaches the vault token as native funds was used with a
+    /// vault that is its own cw20 vault token.
+    #[error("Vault token of vault {vault} is not a native token")]
+    NonNativeVaultToken {
+        /// The address of the vault.
+        vault: Addr,
+    },
+
+    /// An address is not whitelisted to force withdraw from the vault.
+    #[error("Address {address} is not whitelisted to force withdraw")]
+    NotForceWithdrawWhitelisted {
+        /// The address that is not whitelisted.
+        address: Addr,
+    },
+
+    /// A haircut tolerance was not between zero and one.
+    #[error("Haircut must be between 0 and 1, received {haircut}")]
+    InvalidHaircut {
+        /// The received haircut.
+        haircut: Decimal,
+    },
+}
+
+fn fmt_coins(coins: &[Coin]) -> String {
+    coins
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl From<VaultStandardError> for StdError {
+    fn from(err: VaultStandardError) -> Self {
+        match err {
+            VaultStandardError::Std(err) => err,
+            err => StdError::generic_err(err.to_string()),
+        }
+    }
+}
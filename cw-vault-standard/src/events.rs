@@ -0,0 +1,246 @@
+use cosmwasm_std::{Event, Uint128};
+
+#[cfg(feature = "lockup")]
+use crate::extensions::lockup::{
+    UNLOCKING_POSITION_ATTR_KEY, UNLOCKING_POSITION_CREATED_EVENT_TYPE,
+};
+
+/// Type for the event emitted on call to `Deposit`.
+pub const VAULT_DEPOSIT_EVENT_TYPE: &str = "vault_deposit";
+/// Type for the event emitted on call to `Redeem`.
+pub const VAULT_REDEEM_EVENT_TYPE: &str = "vault_redeem";
+/// Type for the event emitted on call to the lockup `WithdrawUnlocked`.
+#[cfg(feature = "lockup")]
+#[cfg_attr(docsrs, doc(cfg(feature = "lockup")))]
+pub const UNLOCKING_POSITION_WITHDRAWN_EVENT_TYPE: &str = "unlocking_position_withdrawn";
+/// Type for the event emitted on call to the force unlock `ForceRedeem` and
+/// `ForceWithdrawUnlocking`.
+#[cfg(feature = "force-unlock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "force-unlock")))]
+pub const FORCE_WITHDRAW_EVENT_TYPE: &str = "force_withdraw";
+
+/// Key for the attribute containing the caller address.
+pub const SENDER_ATTR_KEY: &str = "sender";
+/// Key for the attribute containing the owner of an unlocking position.
+#[cfg(feature = "lockup")]
+#[cfg_attr(docsrs, doc(cfg(feature = "lockup")))]
+pub const OWNER_ATTR_KEY: &str = "owner";
+/// Key for the attribute containing the recipient of vault tokens or base
+/// tokens.
+pub const RECIPIENT_ATTR_KEY: &str = "recipient";
+/// Key for the attribute containing an amount of base tokens.
+pub const ASSETS_ATTR_KEY: &str = "assets";
+/// Key for the attribute containing an amount of vault tokens.
+pub const SHARES_ATTR_KEY: &str = "shares";
+/// Key for the attribute containing the id of an unlocking position, in the
+/// events other than the "unlocking position created" event.
+#[cfg(any(feature = "lockup", feature = "force-unlock"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "lockup", feature = "force-unlock"))))]
+pub const LOCKUP_ID_ATTR_KEY: &str = "lockup_id";
+
+/// A builder for the event emitted on call to `Deposit`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaultDepositEvent {
+    /// The address that deposited the base tokens.
+    pub sender: String,
+    /// The address that received the minted vault tokens.
+    pub recipient: String,
+    /// The amount of base tokens deposited.
+    pub assets: Uint128,
+    /// The amount of vault tokens minted.
+    pub shares: Uint128,
+}
+
+impl VaultDepositEvent {
+    /// Create a new VaultDepositEvent instance.
+    pub fn new(
+        sender: impl Into<String>,
+        recipient: impl Into<String>,
+        assets: Uint128,
+        shares: Uint128,
+    ) -> Self {
+        Self {
+            sender: sender.into(),
+            recipient: recipient.into(),
+            assets,
+            shares,
+        }
+    }
+
+    /// Returns the [`Event`] with type `VAULT_DEPOSIT_EVENT_TYPE`.
+    pub fn into_event(self) -> Event {
+        Event::new(VAULT_DEPOSIT_EVENT_TYPE)
+            .add_attribute(SENDER_ATTR_KEY, self.sender)
+            .add_attribute(RECIPIENT_ATTR_KEY, self.recipient)
+            .add_attribute(ASSETS_ATTR_KEY, self.assets)
+            .add_attribute(SHARES_ATTR_KEY, self.shares)
+    }
+}
+
+/// A builder for the event emitted on call to `Redeem`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaultRedeemEvent {
+    /// The address that redeemed the vault tokens.
+    pub sender: String,
+    /// The address that received the base tokens.
+    pub recipient: String,
+    /// The amount of vault tokens burned.
+    pub shares: Uint128,
+    /// The amount of base tokens returned.
+    pub assets: Uint128,
+}
+
+impl VaultRedeemEvent {
+    /// Create a new VaultRedeemEvent instance.
+    pub fn new(
+        sender: impl Into<String>,
+        recipient: impl Into<String>,
+        shares: Uint128,
+        assets: Uint128,
+    ) -> Self {
+        Self {
+            sender: sender.into(),
+            recipient: recipient.into(),
+            shares,
+            assets,
+        }
+    }
+
+    /// Returns the [`Event`] with type `VAULT_REDEEM_EVENT_TYPE`.
+    pub fn into_event(self) -> Event {
+        Event::new(VAULT_REDEEM_EVENT_TYPE)
+            .add_attribute(SENDER_ATTR_KEY, self.sender)
+            .add_attribute(RECIPIENT_ATTR_KEY, self.recipient)
+            .add_attribute(SHARES_ATTR_KEY, self.shares)
+            .add_attribute(ASSETS_ATTR_KEY, self.assets)
+    }
+}
+
+/// A builder for the event emitted on call to the lockup `Unlock`.
+#[cfg(feature = "lockup")]
+#[cfg_attr(docsrs, doc(cfg(feature = "lockup")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnlockEvent {
+    /// The owner of the created unlocking position.
+    pub owner: String,
+    /// The id of the created unlocking position.
+    pub lockup_id: u64,
+    /// The amount of vault tokens unlocked.
+    pub shares: Uint128,
+}
+
+#[cfg(feature = "lockup")]
+impl UnlockEvent {
+    /// Create a new UnlockEvent instance.
+    pub fn new(owner: impl Into<String>, lockup_id: u64, shares: Uint128) -> Self {
+        Self {
+            owner: owner.into(),
+            lockup_id,
+            shares,
+        }
+    }
+
+    /// Returns the [`Event`] with type `UNLOCKING_POSITION_CREATED_EVENT_TYPE`
+    /// and the lockup id in the `UNLOCKING_POSITION_ATTR_KEY` attribute.
+    pub fn into_event(self) -> Event {
+        Event::new(UNLOCKING_POSITION_CREATED_EVENT_TYPE)
+            .add_attribute(OWNER_ATTR_KEY, self.owner)
+            .add_attribute(UNLOCKING_POSITION_ATTR_KEY, self.lockup_id.to_string())
+            .add_attribute(SHARES_ATTR_KEY, self.shares)
+    }
+}
+
+/// A builder for the event emitted on call to the lockup `WithdrawUnlocked`.
+#[cfg(feature = "lockup")]
+#[cfg_attr(docsrs, doc(cfg(feature = "lockup")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WithdrawUnlockedEvent {
+    /// The id of the withdrawn unlocking position.
+    pub lockup_id: u64,
+    /// The address that received the base tokens.
+    pub recipient: String,
+    /// The amount of base tokens withdrawn.
+    pub assets: Uint128,
+}
+
+#[cfg(feature = "lockup")]
+impl WithdrawUnlockedEvent {
+    /// Create a new WithdrawUnlockedEvent instance.
+    pub fn new(lockup_id: u64, recipient: impl Into<String>, assets: Uint128) -> Self {
+        Self {
+            lockup_id,
+            recipient: recipient.into(),
+            assets,
+        }
+    }
+
+    /// Returns the [`Event`] with type
+    /// `UNLOCKING_POSITION_WITHDRAWN_EVENT_TYPE`.
+    pub fn into_event(self) -> Event {
+        Event::new(UNLOCKING_POSITION_WITHDRAWN_EVENT_TYPE)
+            .add_attribute(LOCKUP_ID_ATTR_KEY, self.lockup_id.to_string())
+            .add_attribute(RECIPIENT_ATTR_KEY, self.recipient)
+            .add_attribute(ASSETS_ATTR_KEY, self.assets)
+    }
+}
+
+/// A builder for the event emitted on call to the force unlock `ForceRedeem`
+/// and `ForceWithdrawUnlocking`.
+#[cfg(feature = "force-unlock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "force-unlock")))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForceWithdrawEvent {
+    /// The whitelisted address that force withdrew.
+    pub sender: String,
+    /// The address that received the base tokens.
+    pub recipient: String,
+    /// The amount of base tokens withdrawn.
+    pub assets: Uint128,
+    /// The amount of vault tokens burned, for `ForceRedeem`.
+    pub shares: Option<Uint128>,
+    /// The id of the unlocking position, for `ForceWithdrawUnlocking`.
+    pub lockup_id: Option<u64>,
+}
+
+#[cfg(feature = "force-unlock")]
+impl ForceWithdrawEvent {
+    /// Create a new ForceWithdrawEvent instance.
+    pub fn new(sender: impl Into<String>, recipient: impl Into<String>, assets: Uint128) -> Self {
+        Self {
+            sender: sender.into(),
+            recipient: recipient.into(),
+            assets,
+            shares: None,
+            lockup_id: None,
+        }
+    }
+
+    /// Set the amount of vault tokens burned by a `ForceRedeem`.
+    pub fn shares(mut self, shares: Uint128) -> Self {
+        self.shares = Some(shares);
+        self
+    }
+
+    /// Set the id of the unlocking position of a `ForceWithdrawUnlocking`.
+    pub fn lockup_id(mut self, lockup_id: u64) -> Self {
+        self.lockup_id = Some(lockup_id);
+        self
+    }
+
+    /// Returns the [`Event`] with type `FORCE_WITHDRAW_EVENT_TYPE`. The
+    /// `SHARES_ATTR_KEY` and `LOCKUP_ID_ATTR_KEY` attributes are only added if
+    /// set.
+    pub fn into_event(self) -> Event {
+        let mut event = Event::new(FORCE_WITHDRAW_EVENT_TYPE)
+            .add_attribute(SENDER_ATTR_KEY, self.sender)
+            .add_attribute(RECIPIENT_ATTR_KEY, self.recipient)
+            .add_attribute(ASSETS_ATTR_KEY, self.assets);
+        if let Some(shares) = self.shares {
+            event = event.add_attribute(SHARES_ATTR_KEY, shares);
+        }
+        if let Some(lockup_id) = self.lockup_id {
+            event = event.add_attribute(LOCKUP_ID_ATTR_KEY, lockup_id.to_string());
+        }
+        event
+    }
+}
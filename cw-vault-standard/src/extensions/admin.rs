@@ -0,0 +1,127 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Binary, Coin, CosmosMsg, StdResult, WasmMsg};
+use cw_utils::Expiration;
+use serde::Serialize;
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `UpdateConfig`.
+pub const CONFIG_UPDATED_EVENT_TYPE: &str = "config_updated";
+/// Key for the attribute in the "config updated" event containing the address
+/// that updated the config.
+pub const CONFIG_UPDATED_SENDER_ATTR_KEY: &str = "sender";
+/// Type for the event emitted on call to `ProposeNewAdmin`.
+pub const ADMIN_PROPOSED_EVENT_TYPE: &str = "admin_proposed";
+/// Type for the event emitted on call to `AcceptAdmin`.
+pub const ADMIN_UPDATED_EVENT_TYPE: &str = "admin_updated";
+/// Type for the event emitted on call to `CancelProposal`.
+pub const ADMIN_PROPOSAL_CANCELLED_EVENT_TYPE: &str = "admin_proposal_cancelled";
+/// Key for the attribute in the "admin proposed" and "admin updated" events
+/// containing the address of the proposed or new admin.
+pub const ADMIN_ATTR_KEY: &str = "admin";
+
+/// Additional ExecuteMsg variants for vaults that enable the Admin extension.
+/// The generic parameter `C` is the vault specific type describing updates to
+/// its config.
+///
+/// The [`ExtensionExecuteMsg`] of this crate can not know the config of a
+/// vault, so it uses the default `C = Binary`: the updates are carried as the
+/// JSON encoding of the vault specific type, which the vault decodes with
+/// `from_json`. Vaults that define their own extension enum can use their
+/// config type directly instead.
+#[cw_serde]
+pub enum AdminExecuteMsg<C = Binary> {
+    /// Callable by vault admin to update the config of the vault.
+    /// Emits an event with type `CONFIG_UPDATED_EVENT_TYPE` with an attribute
+    /// with key `CONFIG_UPDATED_SENDER_ATTR_KEY`.
+    UpdateConfig {
+        /// The updates to apply to the config.
+        updates: C,
+    },
+
+    /// Callable by vault admin to propose a new admin. The new admin must
+    /// accept the proposal via `AcceptAdmin` before it takes effect. Replaces
+    /// any existing proposal.
+    /// Emits an event with type `ADMIN_PROPOSED_EVENT_TYPE` with an attribute
+    /// with key `ADMIN_ATTR_KEY`.
+    ProposeNewAdmin {
+        /// The address of the proposed new admin.
+        address: String,
+        /// An optional expiration of the proposal, after which it can no
+        /// longer be accepted.
+        expiry: Option<Expiration>,
+    },
+
+    /// Callable by the proposed new admin to accept the proposal and become
+    /// the admin of the vault. Must fail if the proposal has expired.
+    /// Emits an event with type `ADMIN_UPDATED_EVENT_TYPE` with an attribute
+    /// with key `ADMIN_ATTR_KEY`.
+    AcceptAdmin {},
+
+    /// Callable by vault admin to cancel the current proposal.
+    /// Emits an event with type `ADMIN_PROPOSAL_CANCELLED_EVENT_TYPE`.
+    CancelProposal {},
+}
+
+impl AdminExecuteMsg {
+    /// Returns an `UpdateConfig` message carrying the JSON encoding of
+    /// `updates`.
+    pub fn update_config(updates: &impl Serialize) -> StdResult<Self> {
+        Ok(Self::UpdateConfig {
+            updates: to_json_binary(updates)?,
+        })
+    }
+
+    /// Convert a [`AdminExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Admin(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Admin extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum AdminQueryMsg {
+    /// Returns `Binary` containing the JSON encoding of the config of the
+    /// vault. The shape of the config is specific to each vault, so it is
+    /// returned as an opaque payload that can be decoded with `from_json`
+    /// by callers that know the config type of the vault.
+    #[returns(Binary)]
+    Config {},
+
+    /// Returns [`AdminResponse`] containing the current admin of the vault.
+    #[returns(AdminResponse)]
+    Admin {},
+
+    /// Returns `Option<PendingAdmin>` containing the current admin proposal,
+    /// if any.
+    #[returns(Option<PendingAdmin>)]
+    PendingAdmin {},
+}
+
+/// Returned by [`AdminQueryMsg::Admin`].
+#[cw_serde]
+pub struct AdminResponse {
+    /// The current admin of the vault, or `None` if the vault has no admin.
+    pub admin: Option<Addr>,
+}
+
+/// A proposal to transfer the admin role of a vault.
+#[cw_serde]
+pub struct PendingAdmin {
+    /// The address of the proposed new admin.
+    pub address: Addr,
+    /// The expiration of the proposal, if any.
+    pub expiry: Option<Expiration>,
+}
@@ -0,0 +1,67 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Allowlist
+/// extension.
+#[cw_serde]
+pub enum AllowlistExecuteMsg {
+    /// Callable by vault admin to update the list of addresses that are
+    /// allowed to deposit into the vault.
+    ///
+    /// A `Deposit` must fail if the recipient of the vault tokens is not on
+    /// the allowlist.
+    UpdateAllowlist {
+        /// Addresses to add to the allowlist.
+        add_addresses: Vec<String>,
+        /// Addresses to remove from the allowlist.
+        remove_addresses: Vec<String>,
+    },
+}
+
+impl AllowlistExecuteMsg {
+    /// Convert a [`AllowlistExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Allowlist(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Allowlist
+/// extension.
+///
+/// The vault standard has no `MaxDeposit` query, so `IsAllowed` takes its
+/// place: an address that is not allowed can deposit nothing. See the
+/// "Deposit and redeem limits" section of the crate docs.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum AllowlistQueryMsg {
+    /// Returns a `Vec<Addr>` containing the addresses that are allowed to
+    /// deposit into the vault.
+    #[returns(Vec<Addr>)]
+    Depositors {
+        /// Return results only after this address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns bool, whether the address is allowed to deposit into the vault
+    /// or not.
+    #[returns(bool)]
+    IsAllowed {
+        /// The address to check
+        address: String,
+    },
+}
@@ -0,0 +1,139 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+use cw_utils::Expiration;
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `RequestRedeem`.
+pub const REDEEM_REQUESTED_EVENT_TYPE: &str = "redeem_requested";
+/// Type for the event emitted on call to `ClaimRedeem`.
+pub const REDEEM_CLAIMED_EVENT_TYPE: &str = "redeem_claimed";
+/// Key for the request id attribute in the "redeem requested" and "redeem
+/// claimed" events.
+pub const REDEEM_REQUEST_ID_ATTR_KEY: &str = "request_id";
+
+/// Additional ExecuteMsg variants for vaults that enable the AsyncRedeem
+/// extension.
+#[cw_serde]
+pub enum AsyncRedeemExecuteMsg {
+    /// Called to request redeeming vault tokens. The native vault token must
+    /// be passed in the funds parameter. The request is fulfilled
+    /// asynchronously by the vault, after which the base tokens can be claimed
+    /// via `ClaimRedeem`.
+    /// Emits an event with type `REDEEM_REQUESTED_EVENT_TYPE` with an
+    /// attribute with key `REDEEM_REQUEST_ID_ATTR_KEY` containing an u64
+    /// request_id.
+    RequestRedeem {
+        /// The amount of vault tokens to redeem.
+        amount: Uint128,
+    },
+
+    /// Claim the base tokens of a fulfilled redeem request. Must fail if the
+    /// request is not yet claimable.
+    /// Emits an event with type `REDEEM_CLAIMED_EVENT_TYPE` with an attribute
+    /// with key `REDEEM_REQUEST_ID_ATTR_KEY`.
+    ClaimRedeem {
+        /// The ID of the redeem request to claim.
+        request_id: u64,
+        /// An optional field containing which address should receive the
+        /// withdrawn base tokens. If not set, the caller address will be
+        /// used instead.
+        recipient: Option<String>,
+    },
+}
+
+impl AsyncRedeemExecuteMsg {
+    /// Convert a [`AsyncRedeemExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::AsyncRedeem(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the AsyncRedeem
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum AsyncRedeemQueryMsg {
+    /// Returns a `Vec<RedeemRequest>` containing all unclaimed redeem requests
+    /// of the `owner`.
+    #[returns(Vec<RedeemRequest>)]
+    RedeemRequests {
+        /// The address of the owner of the redeem requests
+        owner: String,
+        /// Return results only after this request_id
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns a [`RedeemRequest`] with info about a specific redeem request.
+    #[returns(RedeemRequest)]
+    RedeemRequest {
+        /// The ID of the redeem request to query
+        request_id: u64,
+    },
+
+    /// Returns a [`RedeemQueuePosition`] with info about where a pending
+    /// redeem request is in the queue and when it is expected to be
+    /// fulfilled.
+    #[returns(RedeemQueuePosition)]
+    RedeemQueuePosition {
+        /// The ID of the redeem request to query
+        request_id: u64,
+    },
+
+    /// Returns `Uint128` amount of base tokens that are currently available
+    /// to fulfill redeem requests.
+    #[returns(Uint128)]
+    AvailableLiquidity {},
+}
+
+/// The position of a pending redeem request in the queue.
+#[cw_serde]
+pub struct RedeemQueuePosition {
+    /// The number of pending requests that will be fulfilled before this
+    /// request. Zero if the request is next in line.
+    pub position: u64,
+    /// The total amount of vault tokens of the pending requests that will be
+    /// fulfilled before this request.
+    pub shares_ahead: Uint128,
+    /// A `cw_utils::Expiration` containing an estimate of when the request
+    /// will be fulfilled, or `None` if no estimate can be given.
+    pub estimated_fulfillment: Option<Expiration>,
+}
+
+/// The fulfillment status of a redeem request.
+#[cw_serde]
+pub enum RedeemRequestStatus {
+    /// The request has not yet been fulfilled by the vault.
+    Pending,
+    /// The request has been fulfilled and the base tokens can be claimed.
+    Claimable,
+}
+
+/// Info about an unclaimed redeem request.
+#[cw_serde]
+pub struct RedeemRequest {
+    /// The ID of the redeem request.
+    pub id: u64,
+    /// The address of the owner of the redeem request.
+    pub owner: Addr,
+    /// The amount of vault tokens that are being redeemed.
+    pub shares: Uint128,
+    /// The amount of base tokens that can be claimed, or `None` if the
+    /// request has not yet been fulfilled.
+    pub base_token_amount: Option<Uint128>,
+    /// The fulfillment status of the request.
+    pub status: RedeemRequestStatus,
+}
@@ -0,0 +1,79 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Attestation
+/// extension.
+///
+/// The standard `Deposit` message must fail for vaults that enable this
+/// extension, since it does not carry an attestation.
+#[cw_serde]
+pub enum AttestationExecuteMsg {
+    /// Deposit base tokens into the vault, presenting an attestation that
+    /// proves the depositor holds the required claims. The vault must verify
+    /// the attestation against the attestation registry and fail if it is
+    /// invalid, expired, or does not cover all required claims.
+    DepositWithAttestation {
+        /// The amount of base tokens to deposit.
+        amount: Uint128,
+        /// An optional field containing the recipient of the vault token. If
+        /// not set, the caller address will be used instead. The attestation
+        /// must be valid for the recipient.
+        recipient: Option<String>,
+        /// A reference to the attestation, e.g. a credential id or the hash
+        /// of a zero-knowledge proof, as understood by the attestation
+        /// registry.
+        attestation: String,
+    },
+
+    /// Callable by vault admin to update the attestation requirements.
+    /// Fields that are not set are left unchanged.
+    UpdateAttestationConfig {
+        /// The address of the new attestation registry contract.
+        registry: Option<String>,
+        /// The new list of claims that an attestation must cover.
+        required_claims: Option<Vec<String>>,
+    },
+}
+
+impl AttestationExecuteMsg {
+    /// Convert a [`AttestationExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Attestation(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Attestation
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum AttestationQueryMsg {
+    /// Returns [`AttestationConfig`] containing the attestation registry and
+    /// the claims required to deposit into the vault.
+    #[returns(AttestationConfig)]
+    AttestationConfig {},
+}
+
+/// The attestation requirements of a vault.
+#[cw_serde]
+pub struct AttestationConfig {
+    /// The address of the registry contract that attestations are verified
+    /// against.
+    pub registry: Addr,
+    /// The claims that an attestation must cover to be accepted, e.g.
+    /// `"kyc"` or `"accredited_investor"`. The format is defined by the
+    /// registry.
+    pub required_claims: Vec<String>,
+}
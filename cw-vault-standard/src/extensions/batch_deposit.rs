@@ -0,0 +1,137 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `QueueDeposit`.
+pub const DEPOSIT_QUEUED_EVENT_TYPE: &str = "deposit_queued";
+/// Type for the event emitted on call to `SettleBatch`.
+pub const BATCH_SETTLED_EVENT_TYPE: &str = "batch_settled";
+/// Type for the event emitted on call to `ClaimShares`.
+pub const SHARES_CLAIMED_EVENT_TYPE: &str = "shares_claimed";
+/// Key for the batch id attribute in the "deposit queued", "batch settled"
+/// and "shares claimed" events.
+pub const BATCH_ID_ATTR_KEY: &str = "batch_id";
+
+/// Additional ExecuteMsg variants for vaults that enable the BatchDeposit
+/// extension.
+#[cw_serde]
+pub enum BatchDepositExecuteMsg {
+    /// Called to queue a deposit into the current batch. The base tokens must
+    /// be passed in the funds parameter. Vault tokens are minted for the
+    /// whole batch when it is settled, after which they can be claimed via
+    /// `ClaimShares`.
+    /// Emits an event with type `DEPOSIT_QUEUED_EVENT_TYPE` with an attribute
+    /// with key `BATCH_ID_ATTR_KEY` containing the u64 id of the batch.
+    QueueDeposit {
+        /// The amount of base tokens to deposit.
+        amount: Uint128,
+        /// An optional field containing the address that can claim the vault
+        /// tokens. If not set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+
+    /// Settle the current batch, deploying the queued base tokens and minting
+    /// the vault tokens of the batch, and open a new batch. Should only be
+    /// callable by whitelisted keepers or the vault admin.
+    /// Emits an event with type `BATCH_SETTLED_EVENT_TYPE` with an attribute
+    /// with key `BATCH_ID_ATTR_KEY`.
+    SettleBatch {},
+
+    /// Claim the vault tokens of the caller's queued deposit in a settled
+    /// batch. Must fail if the batch is not yet settled.
+    /// Emits an event with type `SHARES_CLAIMED_EVENT_TYPE` with an attribute
+    /// with key `BATCH_ID_ATTR_KEY`.
+    ClaimShares {
+        /// The ID of the batch to claim the vault tokens from.
+        batch_id: u64,
+        /// An optional field containing which address should receive the
+        /// vault tokens. If not set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+}
+
+impl BatchDepositExecuteMsg {
+    /// Convert a [`BatchDepositExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::BatchDeposit(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the BatchDeposit
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum BatchDepositQueryMsg {
+    /// Returns a [`DepositBatch`] with info about the batch that currently
+    /// accepts deposits.
+    #[returns(DepositBatch)]
+    CurrentBatch {},
+
+    /// Returns a [`DepositBatch`] with info about a specific batch.
+    #[returns(DepositBatch)]
+    DepositBatch {
+        /// The ID of the batch to query
+        batch_id: u64,
+    },
+
+    /// Returns a `Vec<QueuedDeposit>` containing all unclaimed queued
+    /// deposits of the `owner`.
+    #[returns(Vec<QueuedDeposit>)]
+    QueuedDeposits {
+        /// The address of the owner of the queued deposits
+        owner: String,
+        /// Return results only after this batch_id
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// The settlement status of a deposit batch.
+#[cw_serde]
+pub enum BatchStatus {
+    /// The batch accepts deposits and has not yet been settled.
+    Open,
+    /// The batch has been settled and the vault tokens can be claimed.
+    Settled,
+}
+
+/// Info about a deposit batch.
+#[cw_serde]
+pub struct DepositBatch {
+    /// The ID of the batch.
+    pub id: u64,
+    /// The total amount of base tokens queued in the batch.
+    pub total_assets: Uint128,
+    /// The total amount of vault tokens minted for the batch, or `None` if
+    /// the batch has not yet been settled.
+    pub total_shares: Option<Uint128>,
+    /// The settlement status of the batch.
+    pub status: BatchStatus,
+    /// The time at which the batch was settled, if it has been settled.
+    pub settled_at: Option<Timestamp>,
+}
+
+/// Info about an unclaimed queued deposit.
+#[cw_serde]
+pub struct QueuedDeposit {
+    /// The ID of the batch the deposit was queued in.
+    pub batch_id: u64,
+    /// The amount of base tokens that were deposited.
+    pub assets: Uint128,
+    /// The amount of vault tokens that can be claimed, or `None` if the batch
+    /// has not yet been settled.
+    pub claimable_shares: Option<Uint128>,
+}
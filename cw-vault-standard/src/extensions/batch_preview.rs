@@ -0,0 +1,31 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+
+/// The name vaults implementing the BatchPreview extension should include in
+/// the `extensions` field of the `VaultStandardInfoResponse`, so that callers
+/// can discover the extension.
+pub const BATCH_PREVIEW_EXTENSION_NAME: &str = "batch-preview";
+
+/// Additional QueryMsg variants for vaults that enable the BatchPreview
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum BatchPreviewQueryMsg {
+    /// Returns a `Vec<Uint128>` containing, for each of `amounts`, the amount
+    /// of vault tokens `PreviewDeposit` would return for it. The results must
+    /// be in the same order as `amounts`.
+    #[returns(Vec<Uint128>)]
+    PreviewDepositBatch {
+        /// The amounts of base tokens to preview depositing.
+        amounts: Vec<Uint128>,
+    },
+
+    /// Returns a `Vec<Uint128>` containing, for each of `amounts`, the amount
+    /// of base tokens `PreviewRedeem` would return for it. The results must
+    /// be in the same order as `amounts`.
+    #[returns(Vec<Uint128>)]
+    PreviewRedeemBatch {
+        /// The amounts of vault tokens to preview redeeming.
+        amounts: Vec<Uint128>,
+    },
+}
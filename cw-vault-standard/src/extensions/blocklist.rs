@@ -0,0 +1,73 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted for every address added to the blocklist.
+pub const ADDRESS_BLOCKED_EVENT_TYPE: &str = "address_blocked";
+/// Type for the event emitted for every address removed from the blocklist.
+pub const ADDRESS_UNBLOCKED_EVENT_TYPE: &str = "address_unblocked";
+/// Key for the attribute in the "address blocked" and "address unblocked"
+/// events containing the address.
+pub const BLOCKLIST_ADDRESS_ATTR_KEY: &str = "address";
+
+/// Additional ExecuteMsg variants for vaults that enable the Blocklist
+/// extension.
+#[cw_serde]
+pub enum BlocklistExecuteMsg {
+    /// Callable by vault admin to update the list of blocked addresses.
+    ///
+    /// A `Deposit` must fail if either the caller or the recipient of the
+    /// vault tokens is blocked. If the vault implements the Cw4626 extension,
+    /// transfers of vault tokens from or to a blocked address must fail as
+    /// well.
+    /// Emits an event with type `ADDRESS_BLOCKED_EVENT_TYPE` or
+    /// `ADDRESS_UNBLOCKED_EVENT_TYPE` for each added or removed address, with
+    /// an attribute with key `BLOCKLIST_ADDRESS_ATTR_KEY`.
+    UpdateBlocklist {
+        /// Addresses to add to the blocklist.
+        add_addresses: Vec<String>,
+        /// Addresses to remove from the blocklist.
+        remove_addresses: Vec<String>,
+    },
+}
+
+impl BlocklistExecuteMsg {
+    /// Convert a [`BlocklistExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Blocklist(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Blocklist
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum BlocklistQueryMsg {
+    /// Returns a `Vec<Addr>` containing the blocked addresses.
+    #[returns(Vec<Addr>)]
+    BlockedAddresses {
+        /// Return results only after this address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns bool, whether the address is blocked or not.
+    #[returns(bool)]
+    IsBlocked {
+        /// The address to check
+        address: String,
+    },
+}
@@ -0,0 +1,76 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Boost extension.
+#[cw_serde]
+pub enum BoostExecuteMsg {
+    /// Recompute the boost of the given address from its current balance of
+    /// locked boost tokens, e.g. after the lock has been extended or has
+    /// decayed. Callable by anyone, so that stale boosts can be corrected.
+    UpdateBoost {
+        /// The address to update the boost for.
+        address: String,
+    },
+}
+
+impl BoostExecuteMsg {
+    /// Convert a [`BoostExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Boost(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Boost extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum BoostQueryMsg {
+    /// Returns [`BoostConfig`] describing how rewards of the vault are
+    /// boosted.
+    #[returns(BoostConfig)]
+    BoostConfig {},
+
+    /// Returns [`UserBoost`] containing the current boost of the given
+    /// address.
+    #[returns(UserBoost)]
+    UserBoost {
+        /// The address to query the boost for.
+        address: String,
+    },
+}
+
+/// The boost configuration of a vault.
+#[cw_serde]
+pub struct BoostConfig {
+    /// The token that must be locked to be eligible for a boost, e.g. a
+    /// vote-escrowed governance token.
+    pub boost_token: String,
+    /// The contract holding the locked boost tokens, if any.
+    pub boost_token_contract: Option<Addr>,
+    /// The maximum multiplier applied to the rewards of a depositor. A
+    /// multiplier of one means no boost.
+    pub max_multiplier: Decimal,
+}
+
+/// The current boost of a depositor.
+#[cw_serde]
+pub struct UserBoost {
+    /// The amount of boost tokens locked by the depositor, as last recorded
+    /// by the vault.
+    pub locked_boost_tokens: Uint128,
+    /// The multiplier currently applied to the rewards of the depositor,
+    /// between one and `max_multiplier`.
+    pub multiplier: Decimal,
+}
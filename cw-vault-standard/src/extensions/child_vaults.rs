@@ -0,0 +1,71 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the ChildVaults
+/// extension.
+#[cw_serde]
+pub enum ChildVaultsExecuteMsg {
+    /// Callable by vault admin to add a child vault that the vault can
+    /// allocate assets to. The child vault must implement the vault standard.
+    AddChildVault {
+        /// The address of the child vault.
+        vault: String,
+    },
+
+    /// Callable by vault admin to remove a child vault. Must fail if the
+    /// vault still holds vault tokens of the child vault.
+    RemoveChildVault {
+        /// The address of the child vault.
+        vault: String,
+    },
+}
+
+impl ChildVaultsExecuteMsg {
+    /// Convert a [`ChildVaultsExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::ChildVaults(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the ChildVaults
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ChildVaultsQueryMsg {
+    /// Returns a `Vec<ChildVault>` containing the child vaults of the vault
+    /// and the position of the vault in each of them.
+    #[returns(Vec<ChildVault>)]
+    ChildVaults {
+        /// Return results only after this child vault address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// The position of a vault in one of its child vaults.
+#[cw_serde]
+pub struct ChildVault {
+    /// The address of the child vault.
+    pub vault: Addr,
+    /// The base token of the child vault.
+    pub base_token: String,
+    /// The amount of vault tokens of the child vault held by the vault.
+    pub shares: Uint128,
+    /// The value of the held vault tokens, denominated in the base token of
+    /// the parent vault.
+    pub value: Uint128,
+}
@@ -0,0 +1,100 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, Decimal, StdResult, Timestamp, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted when the circuit breaker trips.
+pub const CIRCUIT_BREAKER_TRIPPED_EVENT_TYPE: &str = "circuit_breaker_tripped";
+/// Key for the attribute in the "circuit breaker tripped" event containing
+/// the drawdown of the price per share that tripped the breaker.
+pub const DRAWDOWN_ATTR_KEY: &str = "drawdown";
+/// Key for the attribute in the "circuit breaker tripped" event containing
+/// the reference price per share the drawdown was measured against.
+pub const REFERENCE_PRICE_ATTR_KEY: &str = "reference_price";
+/// Key for the attribute in the "circuit breaker tripped" event containing
+/// the price per share when the breaker tripped.
+pub const TRIP_PRICE_ATTR_KEY: &str = "price";
+
+/// Additional ExecuteMsg variants for vaults that enable the CircuitBreaker
+/// extension.
+///
+/// The circuit breaker trips when the price per share drops by more than
+/// `max_drawdown` compared to the highest price per share within the last
+/// `window_secs` seconds. While the breaker is tripped, `Deposit` must fail.
+/// When it trips, the vault emits an event with type
+/// `CIRCUIT_BREAKER_TRIPPED_EVENT_TYPE` with attributes with keys
+/// `DRAWDOWN_ATTR_KEY`, `REFERENCE_PRICE_ATTR_KEY` and `TRIP_PRICE_ATTR_KEY`.
+#[cw_serde]
+pub enum CircuitBreakerExecuteMsg {
+    /// Callable by vault admin to update the circuit breaker config. Fields
+    /// that are not set are left unchanged.
+    UpdateCircuitBreakerConfig {
+        /// The new maximum drawdown before the breaker trips.
+        max_drawdown: Option<Decimal>,
+        /// The new window, in seconds, over which the drawdown is measured.
+        window_secs: Option<u64>,
+    },
+
+    /// Callable by vault admin to reset a tripped circuit breaker, allowing
+    /// deposits again.
+    ResetCircuitBreaker {},
+}
+
+impl CircuitBreakerExecuteMsg {
+    /// Convert a [`CircuitBreakerExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::CircuitBreaker(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the CircuitBreaker
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum CircuitBreakerQueryMsg {
+    /// Returns [`CircuitBreakerConfig`] containing the config of the circuit
+    /// breaker.
+    #[returns(CircuitBreakerConfig)]
+    CircuitBreakerConfig {},
+
+    /// Returns [`CircuitBreakerStatus`] containing the current status of the
+    /// circuit breaker.
+    #[returns(CircuitBreakerStatus)]
+    CircuitBreakerStatus {},
+}
+
+/// The config of the circuit breaker of a vault.
+#[cw_serde]
+pub struct CircuitBreakerConfig {
+    /// The maximum drawdown of the price per share before the breaker trips,
+    /// e.g. `Decimal::percent(10)` for 10%.
+    pub max_drawdown: Decimal,
+    /// The window, in seconds, over which the drawdown is measured.
+    pub window_secs: u64,
+}
+
+/// The current status of the circuit breaker of a vault.
+#[cw_serde]
+pub struct CircuitBreakerStatus {
+    /// Whether the breaker is tripped and deposits are paused.
+    pub tripped: bool,
+    /// The time at which the breaker tripped, if it is tripped.
+    pub tripped_at: Option<Timestamp>,
+    /// The highest price per share within the current window, which the
+    /// drawdown is measured against.
+    pub reference_price: Decimal,
+    /// The current drawdown of the price per share compared to
+    /// `reference_price`.
+    pub current_drawdown: Decimal,
+}
@@ -0,0 +1,28 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Uint128};
+
+/// Additional QueryMsg variants for vaults that enable the Collateral
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum CollateralQueryMsg {
+    /// Returns [`CollateralInfo`] containing the information a credit
+    /// system needs to accept the vault token as collateral.
+    #[returns(CollateralInfo)]
+    CollateralInfo {},
+}
+
+/// Information for credit systems, such as credit managers or lending
+/// protocols, that accept the vault token as collateral.
+#[cw_serde]
+pub struct CollateralInfo {
+    /// The recommended liquidation LTV for positions using the vault token as
+    /// collateral, or `None` if the vault does not make a recommendation.
+    pub recommended_liquidation_ltv: Option<Decimal>,
+    /// Whether the vault implements the ForceUnlock extension, allowing
+    /// liquidators to redeem locked vault tokens immediately.
+    pub force_unlock_supported: bool,
+    /// The amount of base tokens that can currently be withdrawn with an
+    /// immediate `Redeem`, e.g. the idle balance of the vault.
+    pub available_liquidity: Uint128,
+}
@@ -0,0 +1,67 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Uint128};
+
+/// Additional QueryMsg variants for vaults that enable the Composition
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum CompositionQueryMsg {
+    /// Returns [`Composition`] containing the tree of underlying holdings of
+    /// the vault.
+    #[returns(Composition)]
+    Composition {},
+}
+
+/// The underlying holdings of a vault. All values are denominated in the base
+/// token of the vault.
+#[cw_serde]
+pub struct Composition {
+    /// The total value of the holdings. Should be equal to the result of the
+    /// `TotalAssets` query.
+    pub total_value: Uint128,
+    /// The top level holdings of the vault.
+    pub holdings: Vec<Holding>,
+}
+
+/// A single holding of a vault, possibly made up of further holdings.
+#[cw_serde]
+pub struct Holding {
+    /// The kind of the holding.
+    pub kind: HoldingKind,
+    /// The amount held, in the native unit of the holding, e.g. the amount of
+    /// tokens, LP shares or vault tokens.
+    pub amount: Uint128,
+    /// The value of the holding, denominated in the base token of the vault.
+    pub value: Uint128,
+    /// The underlying holdings of this holding, e.g. the assets of an LP
+    /// position or the holdings of a child vault. Empty if the holding is not
+    /// composed of other holdings or if the vault does not look through it.
+    pub children: Vec<Holding>,
+}
+
+/// The kind of a holding.
+#[cw_serde]
+pub enum HoldingKind {
+    /// A plain token.
+    Token {
+        /// The denom of the token, or the address of a cw20 token.
+        denom: String,
+    },
+    /// A liquidity provider position.
+    LpPosition {
+        /// An identifier of the pool, e.g. a pool id or contract address.
+        pool: String,
+    },
+    /// Vault tokens of another vault implementing the vault standard. The
+    /// children of the holding can be obtained by querying `Composition` on
+    /// the child vault, if it enables this extension.
+    Vault {
+        /// The address of the vault.
+        vault: Addr,
+    },
+    /// Any other kind of holding, e.g. a lending or perpetuals position.
+    Other {
+        /// A human readable description of the holding.
+        description: String,
+    },
+}
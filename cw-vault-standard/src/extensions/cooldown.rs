@@ -0,0 +1,26 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cw_utils::{Duration, Expiration};
+
+/// Additional QueryMsg variants for vaults that enable the Cooldown
+/// extension.
+///
+/// A `Redeem` must fail if the caller deposited into the vault less than the
+/// cooldown duration ago. Unlike the Lockup extension, vault tokens are not
+/// locked and no unlocking positions are created.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum CooldownQueryMsg {
+    /// Returns a `cw_utils::Duration` containing the cooldown period that
+    /// must pass after a deposit before the depositor can redeem.
+    #[returns(Duration)]
+    Cooldown {},
+
+    /// Returns an `Option<cw_utils::Expiration>` containing when the cooldown
+    /// of the given address ends, or `None` if the address can redeem
+    /// immediately.
+    #[returns(Option<Expiration>)]
+    RemainingCooldown {
+        /// The address to query the remaining cooldown for.
+        address: String,
+    },
+}
@@ -2,13 +2,12 @@ use crate::msg::{
     ExtensionExecuteMsg, ExtensionQueryMsg, VaultInfoResponse, VaultStandardInfoResponse,
 };
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_binary, Binary, Coin, CosmosMsg, Empty, StdResult, Uint128, WasmMsg};
+use cosmwasm_std::{to_json_binary, Binary, Coin, CosmosMsg, Empty, StdResult, Uint128, WasmMsg};
 use cw20::{
     AllAccountsResponse, AllAllowancesResponse, AllowanceResponse, BalanceResponse,
     DownloadLogoResponse, MarketingInfoResponse, TokenInfoResponse,
 };
 use cw20::{Expiration, Logo};
-use schemars::JsonSchema;
 
 /// The default ExecuteMsg variants that a vault using the Cw4626 extension must
 /// implement. This includes all of the variants from the default
@@ -110,10 +109,14 @@ pub enum Cw4626ExecuteMsg<T = ExtensionExecuteMsg> {
 
 impl Cw4626ExecuteMsg {
     /// Convert a [`Cw4626ExecuteMsg`] into a [`CosmosMsg`].
-    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
         Ok(WasmMsg::Execute {
             contract_addr,
-            msg: to_binary(&self)?,
+            msg: to_json_binary(&self)?,
             funds,
         }
         .into())
@@ -127,10 +130,7 @@ impl Cw4626ExecuteMsg {
 /// then passing it as the generic argument `T` to this enum.
 #[cw_serde]
 #[derive(QueryResponses)]
-pub enum Cw4626QueryMsg<T = ExtensionQueryMsg>
-where
-    T: JsonSchema,
-{
+pub enum Cw4626QueryMsg<T = ExtensionQueryMsg> {
     //--------------------------------------------------------------------------
     // Standard CW20 QueryMsgs
     //--------------------------------------------------------------------------
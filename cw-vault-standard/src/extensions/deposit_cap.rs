@@ -0,0 +1,91 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `UpdateCaps`. The event contains one
+/// attribute per field of the new [`Caps`], with the field name as key.
+pub const CAPS_UPDATED_EVENT_TYPE: &str = "caps_updated";
+
+/// Additional ExecuteMsg variants for vaults that enable the DepositCap
+/// extension.
+#[cw_serde]
+pub enum DepositCapExecuteMsg {
+    /// Callable by vault admin to replace the deposit caps. A cap that is not
+    /// set is removed, i.e. deposits are no longer limited by it.
+    /// Emits an event with type `CAPS_UPDATED_EVENT_TYPE`.
+    UpdateCaps {
+        /// The new cap on the total assets of the vault, or `None` for no cap.
+        total_cap: Option<Uint128>,
+        /// The new cap on the assets deposited per address, or `None` for no
+        /// cap.
+        per_address_cap: Option<Uint128>,
+    },
+}
+
+impl DepositCapExecuteMsg {
+    /// Convert a [`DepositCapExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::DepositCap(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the DepositCap
+/// extension.
+///
+/// The vault standard has no `MaxDeposit` query, so these queries take its
+/// place: the amount an address can still deposit is the smaller of the
+/// remaining total cap, `total_cap - total_assets`, and the remaining
+/// per-address cap. See the "Deposit and redeem limits" section of the
+/// crate docs.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum DepositCapQueryMsg {
+    /// Returns [`Caps`] containing the deposit caps of the vault.
+    #[returns(Caps)]
+    Caps {},
+
+    /// Returns [`Utilization`] describing how much of the total cap is in
+    /// use. Front-ends should show the vault as full when
+    /// [`Utilization::utilization`] is at least one.
+    #[returns(Utilization)]
+    Utilization {},
+}
+
+/// The deposit caps of a vault, denominated in base tokens. A `Deposit` that
+/// would cause either cap to be exceeded must fail.
+#[cw_serde]
+pub struct Caps {
+    /// The maximum amount of total assets the vault accepts, or `None` if
+    /// the total assets are not capped.
+    pub total_cap: Option<Uint128>,
+    /// The maximum amount of assets a single address may have deposited, or
+    /// `None` if deposits per address are not capped.
+    pub per_address_cap: Option<Uint128>,
+}
+
+/// The utilization of the total deposit cap of a vault.
+#[cw_serde]
+pub struct Utilization {
+    /// The current total assets of the vault, denominated in base tokens.
+    pub total_assets: Uint128,
+    /// The maximum amount of total assets the vault accepts, or `None` if
+    /// the total assets are not capped.
+    pub total_cap: Option<Uint128>,
+    /// The ratio of `total_assets` to `total_cap`, or `None` if the total
+    /// assets are not capped. The vault is full when this is at least one.
+    /// It exceeds one if the cap was lowered below the current total assets.
+    /// If the cap is zero the vault accepts no deposits and this is one.
+    pub utilization: Option<Decimal>,
+}
@@ -0,0 +1,96 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Coin, CosmosMsg, Decimal, Int128, StdResult, Timestamp, WasmMsg,
+};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `RollEpoch`.
+pub const EPOCH_ROLLED_EVENT_TYPE: &str = "epoch_rolled";
+/// Key for the attribute in the "epoch rolled" event containing the u64
+/// number of the epoch that was settled.
+pub const SETTLED_EPOCH_ATTR_KEY: &str = "settled_epoch";
+/// Key for the attribute in the "epoch rolled" event containing the profit or
+/// loss of the settled epoch, denominated in base tokens.
+pub const EPOCH_PNL_ATTR_KEY: &str = "pnl";
+/// Key for the attribute in the "epoch rolled" event containing the u64
+/// number of the newly started epoch.
+pub const NEW_EPOCH_ATTR_KEY: &str = "new_epoch";
+
+/// Additional ExecuteMsg variants for vaults that enable the Epoch extension.
+#[cw_serde]
+pub enum EpochExecuteMsg {
+    /// Settle the current epoch and start the next one. Should only be
+    /// callable once the current epoch has ended, by whitelisted keepers or by
+    /// anyone, depending on the vault implementation.
+    /// Emits an event with type `EPOCH_ROLLED_EVENT_TYPE` with attributes with
+    /// keys `SETTLED_EPOCH_ATTR_KEY`, `EPOCH_PNL_ATTR_KEY` and
+    /// `NEW_EPOCH_ATTR_KEY`.
+    RollEpoch {},
+}
+
+impl EpochExecuteMsg {
+    /// Convert a [`EpochExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Epoch(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Epoch extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum EpochQueryMsg {
+    /// Returns [`EpochInfo`] describing the current epoch.
+    #[returns(EpochInfo)]
+    CurrentEpoch {},
+
+    /// Returns a `Vec<EpochResult>` containing the results of settled epochs,
+    /// in ascending order of epoch number.
+    #[returns(Vec<EpochResult>)]
+    EpochResults {
+        /// Return results only after this epoch number
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// Information about the current epoch of a vault.
+#[cw_serde]
+pub struct EpochInfo {
+    /// The number of the current epoch. The first epoch is number 1.
+    pub epoch: u64,
+    /// The time at which the current epoch started.
+    pub start: Timestamp,
+    /// The time at which the current epoch ends and `RollEpoch` can be
+    /// called.
+    pub end: Timestamp,
+}
+
+/// The result of a settled epoch.
+#[cw_serde]
+pub struct EpochResult {
+    /// The number of the epoch.
+    pub epoch: u64,
+    /// The time at which the epoch started.
+    pub start: Timestamp,
+    /// The time at which the epoch was settled.
+    pub settled_at: Timestamp,
+    /// The profit (positive) or loss (negative) of the vault during the
+    /// epoch, denominated in base tokens.
+    pub pnl: Int128,
+    /// The price of one vault token, denominated in base tokens, at the time
+    /// the epoch was settled.
+    pub price_per_share: Decimal,
+}
@@ -0,0 +1,79 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, Decimal, StdResult, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `DistributeFees`, once per recipient.
+pub const FEES_DISTRIBUTED_EVENT_TYPE: &str = "fees_distributed";
+/// Key for the attribute in the "fees distributed" event containing the
+/// address of the recipient.
+pub const FEES_DISTRIBUTED_RECIPIENT_ATTR_KEY: &str = "recipient";
+/// Key for the attribute in the "fees distributed" event containing the
+/// distributed fees, formatted as a comma separated list of coins.
+pub const FEES_DISTRIBUTED_AMOUNT_ATTR_KEY: &str = "amount";
+
+/// Additional ExecuteMsg variants for vaults that enable the FeeSplit
+/// extension.
+#[cw_serde]
+pub enum FeeSplitExecuteMsg {
+    /// Callable by vault admin to update how collected fees are split among
+    /// recipients. The weights of the recipients must sum up to one.
+    UpdateFeeSplit {
+        /// The new fee recipients.
+        recipients: Vec<FeeSplitRecipient<String>>,
+    },
+
+    /// Distribute the collected fees held by the vault to the fee recipients
+    /// according to their weights. Callable by anyone.
+    /// Emits an event with type `FEES_DISTRIBUTED_EVENT_TYPE` for each
+    /// recipient, with attributes with keys
+    /// `FEES_DISTRIBUTED_RECIPIENT_ATTR_KEY` and
+    /// `FEES_DISTRIBUTED_AMOUNT_ATTR_KEY`.
+    DistributeFees {},
+}
+
+impl FeeSplitExecuteMsg {
+    /// Convert a [`FeeSplitExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::FeeSplit(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the FeeSplit
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum FeeSplitQueryMsg {
+    /// Returns a `Vec<FeeSplitRecipient<Addr>>` containing the recipients
+    /// that collected fees are split among.
+    #[returns(Vec<FeeSplitRecipient<Addr>>)]
+    FeeSplit {},
+
+    /// Returns a `Vec<Coin>` containing the collected fees that have not yet
+    /// been distributed.
+    #[returns(Vec<Coin>)]
+    UndistributedFees {},
+}
+
+/// A recipient of a share of the collected fees, e.g. a treasury, strategist
+/// or insurance fund.
+#[cw_serde]
+pub struct FeeSplitRecipient<T> {
+    /// The address of the recipient.
+    pub address: T,
+    /// The share of the collected fees the recipient receives.
+    pub weight: Decimal,
+    /// An optional human readable label of the recipient, e.g. "treasury".
+    pub label: Option<String>,
+}
@@ -0,0 +1,71 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Uint128};
+
+/// Additional QueryMsg variants for vaults that enable the FeeTiers
+/// extension.
+///
+/// The `PreviewDeposit` and `PreviewRedeem` queries of the vault standard do
+/// not know the depositor and must use the default fees. Integrators should
+/// use the `EffectiveFees` query to compute accurate previews for addresses
+/// that qualify for a fee tier.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum FeeTiersQueryMsg {
+    /// Returns a `Vec<FeeTier>` containing the fee tiers of the vault, in
+    /// ascending order of priority.
+    #[returns(Vec<FeeTier>)]
+    FeeTiers {},
+
+    /// Returns [`EffectiveFees`] containing the fees charged to the given
+    /// address.
+    #[returns(EffectiveFees)]
+    EffectiveFees {
+        /// The address to query the effective fees for.
+        address: String,
+    },
+}
+
+/// The requirement an address must fulfill to qualify for a fee tier.
+#[cw_serde]
+pub enum FeeTierRequirement {
+    /// The position of the address in the vault must be worth at least the
+    /// given amount of base tokens.
+    MinPosition {
+        /// The minimum value of the position, denominated in base tokens.
+        base_tokens: Uint128,
+    },
+    /// The address must have the given holder status, e.g. `"partner"` or
+    /// `"governance_staker"`. The meaning of the status is defined by the
+    /// vault implementation.
+    HolderStatus {
+        /// The required holder status.
+        status: String,
+    },
+}
+
+/// A fee tier with discounted fees. All fees are expressed as a fraction,
+/// e.g. `Decimal::percent(1)` for a 1% fee.
+#[cw_serde]
+pub struct FeeTier {
+    /// The name of the fee tier.
+    pub name: String,
+    /// The requirement to qualify for the fee tier.
+    pub requirement: FeeTierRequirement,
+    /// The fee charged on the base tokens deposited into the vault.
+    pub deposit_fee: Decimal,
+    /// The fee charged on the base tokens withdrawn from the vault.
+    pub withdrawal_fee: Decimal,
+}
+
+/// The fees charged to a specific address.
+#[cw_serde]
+pub struct EffectiveFees {
+    /// The name of the fee tier the address qualifies for, or `None` if the
+    /// default fees apply. If the address qualifies for multiple tiers, the
+    /// one with the highest priority applies.
+    pub tier: Option<String>,
+    /// The fee charged on the base tokens deposited into the vault.
+    pub deposit_fee: Decimal,
+    /// The fee charged on the base tokens withdrawn from the vault.
+    pub withdrawal_fee: Decimal,
+}
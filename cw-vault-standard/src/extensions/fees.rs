@@ -0,0 +1,121 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, CosmosMsg, Decimal, StdResult, Timestamp, Uint128, WasmMsg,
+};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// The maximum deposit fee a vault may charge.
+pub const MAX_DEPOSIT_FEE: Decimal = Decimal::percent(5);
+/// The maximum withdrawal fee a vault may charge.
+pub const MAX_WITHDRAWAL_FEE: Decimal = Decimal::percent(5);
+/// The maximum performance fee a vault may charge.
+pub const MAX_PERFORMANCE_FEE: Decimal = Decimal::percent(50);
+/// The maximum yearly management fee a vault may charge.
+pub const MAX_MANAGEMENT_FEE: Decimal = Decimal::percent(10);
+
+/// Type for the event emitted on call to `UpdateFeeConfig`. The event contains
+/// one attribute per field of the updated [`FeeConfig`], with the field name
+/// as key.
+pub const FEE_CONFIG_UPDATED_EVENT_TYPE: &str = "fee_config_updated";
+/// Type for the event emitted on call to `CrystallizeFees`.
+pub const FEES_CRYSTALLIZED_EVENT_TYPE: &str = "fees_crystallized";
+/// Key for the attribute in the "fees crystallized" event containing the
+/// amount of base tokens collected as performance fee.
+pub const FEES_CRYSTALLIZED_AMOUNT_ATTR_KEY: &str = "amount";
+/// Key for the attribute in the "fees crystallized" event containing the new
+/// high-water mark.
+pub const FEES_CRYSTALLIZED_HIGH_WATER_MARK_ATTR_KEY: &str = "high_water_mark";
+
+/// Additional ExecuteMsg variants for vaults that enable the Fees extension.
+#[cw_serde]
+pub enum FeesExecuteMsg {
+    /// Callable by vault admin to update the fee config. Fields that are not
+    /// set are left unchanged. Must fail if any of the fees exceed their
+    /// respective maximum, e.g. [`MAX_PERFORMANCE_FEE`].
+    /// Emits an event with type `FEE_CONFIG_UPDATED_EVENT_TYPE` containing
+    /// the new fee config.
+    UpdateFeeConfig {
+        /// The new deposit fee. May not exceed [`MAX_DEPOSIT_FEE`].
+        deposit_fee: Option<Decimal>,
+        /// The new withdrawal fee. May not exceed [`MAX_WITHDRAWAL_FEE`].
+        withdrawal_fee: Option<Decimal>,
+        /// The new performance fee. May not exceed [`MAX_PERFORMANCE_FEE`].
+        performance_fee: Option<Decimal>,
+        /// The new management fee. May not exceed [`MAX_MANAGEMENT_FEE`].
+        management_fee: Option<Decimal>,
+        /// The new address that receives the collected fees.
+        fee_recipient: Option<String>,
+    },
+
+    /// Crystallize the accrued performance fees, i.e. collect them and send
+    /// them to the fee recipient, and update the high-water mark. Callable by
+    /// anyone.
+    /// Emits an event with type `FEES_CRYSTALLIZED_EVENT_TYPE` with attributes
+    /// with keys `FEES_CRYSTALLIZED_AMOUNT_ATTR_KEY` and
+    /// `FEES_CRYSTALLIZED_HIGH_WATER_MARK_ATTR_KEY`.
+    CrystallizeFees {},
+}
+
+impl FeesExecuteMsg {
+    /// Convert a [`FeesExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Fees(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Fees extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum FeesQueryMsg {
+    /// Returns [`FeeConfig`] containing the fees charged by the vault.
+    #[returns(FeeConfig)]
+    FeeConfig {},
+
+    /// Returns [`PerformanceFeeState`] containing the current high-water mark
+    /// and the accrued but not yet collected performance fees.
+    #[returns(PerformanceFeeState)]
+    PerformanceFeeState {},
+}
+
+/// The fees charged by a vault. All fees are expressed as a fraction, e.g.
+/// `Decimal::percent(1)` for a 1% fee.
+#[cw_serde]
+pub struct FeeConfig {
+    /// The fee charged on the base tokens deposited into the vault.
+    pub deposit_fee: Decimal,
+    /// The fee charged on the base tokens withdrawn from the vault.
+    pub withdrawal_fee: Decimal,
+    /// The fee charged on the profits generated by the vault.
+    pub performance_fee: Decimal,
+    /// The yearly fee charged on the total assets managed by the vault.
+    pub management_fee: Decimal,
+    /// The address that receives the collected fees.
+    pub fee_recipient: Addr,
+}
+
+/// The state of the performance fee accounting of a vault.
+#[cw_serde]
+pub struct PerformanceFeeState {
+    /// The highest price per vault token, denominated in base tokens, at which
+    /// performance fees have been crystallized. Performance fees are only
+    /// charged on profits above this value.
+    pub high_water_mark: Decimal,
+    /// The amount of base tokens accrued as performance fee that have not yet
+    /// been collected.
+    pub accrued_fees: Uint128,
+    /// The time of the last crystallization, or `None` if fees have never
+    /// been crystallized.
+    pub last_crystallization: Option<Timestamp>,
+}
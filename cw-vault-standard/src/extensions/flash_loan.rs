@@ -0,0 +1,96 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Binary, Coin, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// The name vaults implementing the FlashLoan extension should include in the
+/// `extensions` field of the `VaultStandardInfoResponse`, so that routers can
+/// discover the extension.
+pub const FLASH_LOAN_EXTENSION_NAME: &str = "flash-loan";
+
+/// Additional ExecuteMsg variants for vaults that enable the FlashLoan
+/// extension.
+#[cw_serde]
+pub enum FlashLoanExecuteMsg {
+    /// Borrow idle base tokens from the vault for the duration of one
+    /// transaction. The vault sends `amount` base tokens to the caller
+    /// together with a [`FlashLoanReceiverMsg::FlashLoanCallback`] message,
+    /// after which the vault must verify that its base token balance has
+    /// increased by at least the flash loan fee, and fail otherwise.
+    ///
+    /// The vault must not allow deposits, redeems or other flash loans while
+    /// a flash loan is in progress, to prevent reentrancy.
+    FlashLoan {
+        /// The amount of base tokens to borrow.
+        amount: Uint128,
+        /// An arbitrary message that is passed on to the caller in the
+        /// callback.
+        msg: Binary,
+    },
+}
+
+impl FlashLoanExecuteMsg {
+    /// Convert a [`FlashLoanExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::FlashLoan(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// The ExecuteMsg that contracts taking flash loans must implement.
+#[cw_serde]
+pub enum FlashLoanReceiverMsg {
+    /// Called by the vault after sending the borrowed base tokens. Before
+    /// returning, the receiver must send `amount` plus `fee` base tokens back
+    /// to the vault.
+    FlashLoanCallback {
+        /// The borrowed base tokens.
+        amount: Coin,
+        /// The amount of base tokens to pay as fee on top of `amount`.
+        fee: Uint128,
+        /// The message passed in to `FlashLoan`.
+        msg: Binary,
+    },
+}
+
+impl FlashLoanReceiverMsg {
+    /// Convert a [`FlashLoanReceiverMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&self)?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the FlashLoan
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum FlashLoanQueryMsg {
+    /// Returns `Decimal` fee charged on flash loans, as a fraction of the
+    /// borrowed amount.
+    #[returns(Decimal)]
+    FlashLoanFee {},
+
+    /// Returns `Uint128` maximum amount of base tokens that can currently be
+    /// borrowed.
+    #[returns(Uint128)]
+    MaxFlashLoan {},
+}
@@ -1,8 +1,12 @@
-use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_binary, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
 
 use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
 
+/// The name vaults implementing the ForceUnlock extension should include in
+/// the `extensions` field of the `VaultStandardInfoResponse`.
+pub const FORCE_UNLOCK_EXTENSION_NAME: &str = "force-unlock";
+
 /// Additional ExecuteMsg variants for vaults that enable the ForceUnlock
 /// extension.
 #[cw_serde]
@@ -44,10 +48,14 @@ pub enum ForceUnlockExecuteMsg {
 
 impl ForceUnlockExecuteMsg {
     /// Convert a [`ForceUnlockExecuteMsg`] into a [`CosmosMsg`].
-    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
         Ok(WasmMsg::Execute {
             contract_addr,
-            msg: to_binary(&VaultStandardExecuteMsg::VaultExtension(
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
                 ExtensionExecuteMsg::ForceUnlock(self),
             ))?,
             funds,
@@ -55,3 +63,27 @@ impl ForceUnlockExecuteMsg {
         .into())
     }
 }
+
+/// Additional QueryMsg variants for vaults that enable the ForceUnlock
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ForceUnlockQueryMsg {
+    /// Returns a `Vec<Addr>` containing the addresses that are whitelisted to
+    /// call ForceRedeem and ForceWithdrawUnlocking.
+    #[returns(Vec<Addr>)]
+    ForceWithdrawWhitelist {
+        /// Return results only after this address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns bool, whether the address is whitelisted to call ForceRedeem
+    /// and ForceWithdrawUnlocking or not.
+    #[returns(bool)]
+    IsForceWithdrawWhitelisted {
+        /// The address to check
+        address: String,
+    },
+}
@@ -0,0 +1,95 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Hooks extension.
+#[cw_serde]
+pub enum HooksExecuteMsg {
+    /// Callable by vault admin to register a hook contract. After every
+    /// deposit and redeem the vault must call the hook contract with a
+    /// [`VaultHookMsg`].
+    AddHook {
+        /// The address of the hook contract.
+        contract: String,
+    },
+
+    /// Callable by vault admin to unregister a hook contract.
+    RemoveHook {
+        /// The address of the hook contract.
+        contract: String,
+    },
+}
+
+impl HooksExecuteMsg {
+    /// Convert a [`HooksExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Hooks(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// The ExecuteMsg that hook contracts registered via
+/// [`HooksExecuteMsg::AddHook`] must implement.
+#[cw_serde]
+pub enum VaultHookMsg {
+    /// Called by the vault after a deposit.
+    AfterDeposit {
+        /// The address that made the deposit.
+        sender: String,
+        /// The address that received the vault tokens.
+        recipient: String,
+        /// The amount of base tokens deposited.
+        assets: Uint128,
+        /// The amount of vault tokens minted.
+        shares: Uint128,
+    },
+
+    /// Called by the vault after a redeem.
+    AfterRedeem {
+        /// The address that redeemed the vault tokens.
+        sender: String,
+        /// The address that received the base tokens.
+        recipient: String,
+        /// The amount of base tokens withdrawn.
+        assets: Uint128,
+        /// The amount of vault tokens burned.
+        shares: Uint128,
+    },
+}
+
+impl VaultHookMsg {
+    /// Convert a [`VaultHookMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(self, contract_addr: String) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&self)?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Hooks extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum HooksQueryMsg {
+    /// Returns a `Vec<Addr>` containing the registered hook contracts.
+    #[returns(Vec<Addr>)]
+    Hooks {
+        /// Return results only after this address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
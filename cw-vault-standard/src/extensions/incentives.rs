@@ -0,0 +1,107 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Timestamp, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `AddIncentive`.
+pub const INCENTIVE_ADDED_EVENT_TYPE: &str = "incentive_added";
+/// Key for the attribute in the "incentive added" event containing the u64 id
+/// of the incentive program.
+pub const INCENTIVE_ID_ATTR_KEY: &str = "incentive_id";
+/// Key for the attribute in the "incentive added" event containing the
+/// address that funded the incentive program.
+pub const INCENTIVE_FUNDER_ATTR_KEY: &str = "funder";
+/// Key for the attribute in the "incentive added" event containing the
+/// budget of the incentive program, formatted as a coin, e.g. `100uosmo`.
+pub const INCENTIVE_BUDGET_ATTR_KEY: &str = "budget";
+
+/// Additional ExecuteMsg variants for vaults that enable the Incentives
+/// extension.
+#[cw_serde]
+pub enum IncentivesExecuteMsg {
+    /// Attach a reward budget to the vault, to be distributed to the vault
+    /// token holders pro-rata over the given period. Callable by anyone, e.g.
+    /// gauges or bribe markets. The budget must be passed in the funds
+    /// parameter as a single coin.
+    /// Emits an event with type `INCENTIVE_ADDED_EVENT_TYPE` with attributes
+    /// with keys `INCENTIVE_ID_ATTR_KEY`, `INCENTIVE_FUNDER_ATTR_KEY` and
+    /// `INCENTIVE_BUDGET_ATTR_KEY`.
+    AddIncentive {
+        /// The time at which the distribution starts. If not set, the
+        /// distribution starts immediately.
+        start: Option<Timestamp>,
+        /// The time at which the distribution ends.
+        end: Timestamp,
+    },
+
+    /// Callable by the funder of an incentive program to withdraw the part of
+    /// the budget that was not distributed. Only possible after the program
+    /// has ended.
+    WithdrawUndistributed {
+        /// The ID of the incentive program.
+        incentive_id: u64,
+        /// An optional field containing which address should receive the
+        /// withdrawn tokens. If not set, the caller address will be used
+        /// instead.
+        recipient: Option<String>,
+    },
+}
+
+impl IncentivesExecuteMsg {
+    /// Convert a [`IncentivesExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Incentives(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Incentives
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum IncentivesQueryMsg {
+    /// Returns a `Vec<IncentiveProgram>` containing the incentive programs
+    /// that have not yet ended, in ascending order of id.
+    #[returns(Vec<IncentiveProgram>)]
+    ActiveIncentives {
+        /// Return results only after this incentive id
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns [`IncentiveProgram`] for the incentive program with the given
+    /// id.
+    #[returns(IncentiveProgram)]
+    Incentive {
+        /// The ID of the incentive program.
+        incentive_id: u64,
+    },
+}
+
+/// An incentive program attached to a vault by a third party.
+#[cw_serde]
+pub struct IncentiveProgram {
+    /// The ID of the incentive program.
+    pub id: u64,
+    /// The address that funded the incentive program.
+    pub funder: Addr,
+    /// The total reward budget of the program.
+    pub budget: Coin,
+    /// The part of the budget that has already been distributed.
+    pub distributed: Coin,
+    /// The time at which the distribution starts.
+    pub start: Timestamp,
+    /// The time at which the distribution ends.
+    pub end: Timestamp,
+}
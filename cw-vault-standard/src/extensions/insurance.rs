@@ -0,0 +1,63 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Insurance
+/// extension.
+#[cw_serde]
+pub enum InsuranceExecuteMsg {
+    /// Callable by vault admin to add base tokens to the insurance fund. The
+    /// base tokens must be passed in the funds parameter.
+    FundInsurance {},
+
+    /// Callable by vault admin to draw base tokens from the insurance fund
+    /// into the assets of the vault, e.g. to cover a loss.
+    DrawInsurance {
+        /// The amount of base tokens to draw.
+        amount: Uint128,
+    },
+}
+
+impl InsuranceExecuteMsg {
+    /// Convert a [`InsuranceExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Insurance(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Insurance
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum InsuranceQueryMsg {
+    /// Returns [`InsuranceFund`] containing the state of the insurance fund.
+    #[returns(InsuranceFund)]
+    InsuranceFund {},
+}
+
+/// The state of the insurance fund of a vault. All amounts are denominated in
+/// base tokens.
+#[cw_serde]
+pub struct InsuranceFund {
+    /// The amount of base tokens currently held in the insurance fund.
+    pub balance: Uint128,
+    /// The amount of base tokens the vault aims to hold in the insurance
+    /// fund.
+    pub target: Uint128,
+    /// Whether the insurance fund is included in the amount returned by the
+    /// `TotalAssets` query. If false, the insurance fund is first-loss
+    /// capital that does not belong to the depositors.
+    pub included_in_total_assets: bool,
+}
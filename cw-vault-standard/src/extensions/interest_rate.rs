@@ -0,0 +1,155 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, StdResult};
+
+/// Additional QueryMsg variants for vaults that enable the InterestRate
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum InterestRateQueryMsg {
+    /// Returns a `Decimal` containing the current utilization of the vault,
+    /// i.e. the share of the total assets that is lent out to borrowers.
+    #[returns(Decimal)]
+    Utilization {},
+
+    /// Returns [`Rates`] containing the current borrow and supply rates.
+    #[returns(Rates)]
+    Rates {},
+
+    /// Returns [`InterestRateModel`] containing the parameters of the
+    /// interest rate model of the vault.
+    #[returns(InterestRateModel)]
+    InterestRateModel {},
+}
+
+/// The current interest rates of a lending vault, as annual rates, not
+/// compounded.
+#[cw_serde]
+pub struct Rates {
+    /// The rate paid by borrowers.
+    pub borrow_rate: Decimal,
+    /// The rate earned by depositors, after the reserve factor.
+    pub supply_rate: Decimal,
+}
+
+/// The parameters of a kinked (two-slope) interest rate model. Vaults using
+/// a different model should return the parameters of the closest kinked
+/// approximation.
+#[cw_serde]
+pub struct InterestRateModel {
+    /// The borrow rate at zero utilization.
+    pub base_rate: Decimal,
+    /// The utilization at which the slope of the borrow rate changes from
+    /// `slope_1` to `slope_2`.
+    pub optimal_utilization: Decimal,
+    /// The increase of the borrow rate from zero to optimal utilization.
+    pub slope_1: Decimal,
+    /// The increase of the borrow rate from optimal to full utilization.
+    pub slope_2: Decimal,
+    /// The share of the interest paid by borrowers that is kept as reserves
+    /// instead of being paid to depositors.
+    pub reserve_factor: Decimal,
+}
+
+impl InterestRateModel {
+    /// Returns the borrow and supply rates at the given utilization.
+    pub fn rates(&self, utilization: Decimal) -> StdResult<Rates> {
+        let utilization = utilization.min(Decimal::one());
+        let borrow_rate = if utilization <= self.optimal_utilization {
+            let slope = if self.optimal_utilization.is_zero() {
+                Decimal::zero()
+            } else {
+                self.slope_1.checked_mul(utilization)? / self.optimal_utilization
+            };
+            self.base_rate.checked_add(slope)?
+        } else {
+            let excess = (utilization - self.optimal_utilization)
+                / (Decimal::one() - self.optimal_utilization);
+            self.base_rate
+                .checked_add(self.slope_1)?
+                .checked_add(self.slope_2.checked_mul(excess)?)?
+        };
+        let supply_rate = borrow_rate
+            .checked_mul(utilization)?
+            .checked_mul(Decimal::one() - self.reserve_factor.min(Decimal::one()))?;
+
+        Ok(Rates {
+            borrow_rate,
+            supply_rate,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn model(optimal_utilization: &str, reserve_factor: &str) -> InterestRateModel {
+        InterestRateModel {
+            base_rate: dec("0.02"),
+            optimal_utilization: dec(optimal_utilization),
+            slope_1: dec("0.04"),
+            slope_2: dec("0.6"),
+            reserve_factor: dec(reserve_factor),
+        }
+    }
+
+    fn assert_rates(model: &InterestRateModel, utilization: &str, borrow: &str, supply: &str) {
+        assert_eq!(
+            model.rates(dec(utilization)).unwrap(),
+            Rates {
+                borrow_rate: dec(borrow),
+                supply_rate: dec(supply),
+            }
+        );
+    }
+
+    #[test]
+    fn rates_follow_both_slopes() {
+        let model = model("0.8", "0.1");
+        assert_rates(&model, "0", "0.02", "0");
+        assert_rates(&model, "0.4", "0.04", "0.0144");
+        assert_rates(&model, "0.8", "0.06", "0.0432");
+        assert_rates(&model, "0.9", "0.36", "0.2916");
+        assert_rates(&model, "1", "0.66", "0.594");
+    }
+
+    #[test]
+    fn rates_clamp_utilization_to_one() {
+        let model = model("0.8", "0.1");
+        assert_rates(&model, "1.5", "0.66", "0.594");
+    }
+
+    #[test]
+    fn rates_with_zero_optimal_utilization() {
+        let model = model("0", "0.1");
+        assert_rates(&model, "0", "0.02", "0");
+        assert_rates(&model, "0.5", "0.36", "0.162");
+        assert_rates(&model, "1", "0.66", "0.594");
+    }
+
+    #[test]
+    fn rates_with_full_optimal_utilization() {
+        let model = model("1", "0.1");
+        assert_rates(&model, "0.5", "0.04", "0.018");
+        assert_rates(&model, "1", "0.06", "0.054");
+        assert_rates(&model, "1.5", "0.06", "0.054");
+    }
+
+    #[test]
+    fn rates_with_zero_reserve_factor() {
+        let model = model("0.8", "0");
+        assert_rates(&model, "0.8", "0.06", "0.048");
+    }
+
+    #[test]
+    fn rates_clamp_reserve_factor_to_one() {
+        let model = model("0.8", "1.5");
+        assert_rates(&model, "0.8", "0.06", "0");
+    }
+}
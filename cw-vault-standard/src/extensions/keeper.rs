@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, Decimal, StdResult, Timestamp, WasmMsg};
 
 use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
 
@@ -14,6 +14,18 @@ pub struct KeeperJob {
     pub whitelisted_keepers: Vec<Addr>,
 }
 
+/// The target weight of a single position held by the vault, used when
+/// rebalancing.
+#[cw_serde]
+pub struct TargetWeight {
+    /// An identifier of the position, e.g. a pool id, denom or contract
+    /// address. The format is defined by the vault implementation.
+    pub position: String,
+    /// The share of the vault's total assets that should be allocated to the
+    /// position. The weights of all positions should sum up to one.
+    pub weight: Decimal,
+}
+
 /// Additional ExecuteMsg variants for vaults that enable the Keeper extension.
 #[cw_serde]
 pub enum KeeperExecuteMsg {
@@ -39,14 +51,26 @@ pub enum KeeperExecuteMsg {
         /// The ID of the job to execute
         job_id: u64,
     },
+    /// Rebalance the positions held by the vault. Should only be callable by
+    /// whitelisted keepers. If `target_weights` is set the vault should
+    /// rebalance towards these weights, otherwise the vault should use its
+    /// currently configured target weights.
+    Rebalance {
+        /// Optional new target weights of the positions held by the vault.
+        target_weights: Option<Vec<TargetWeight>>,
+    },
 }
 
 impl KeeperExecuteMsg {
     /// Convert a [`KeeperExecuteMsg`] into a [`CosmosMsg`].
-    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
         Ok(WasmMsg::Execute {
             contract_addr,
-            msg: to_binary(&VaultStandardExecuteMsg::VaultExtension(
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
                 ExtensionExecuteMsg::Keeper(self),
             ))?,
             funds,
@@ -74,4 +98,23 @@ pub enum KeeperQueryMsg {
         /// The ID of the job to check whether it is ready to be executed
         job_id: u64,
     },
+    /// Returns [`RebalanceStatusResponse`] with information about the last
+    /// rebalance and how far the vault has drifted from its target weights.
+    #[returns(RebalanceStatusResponse)]
+    RebalanceStatus {},
+}
+
+/// Returned by [`KeeperQueryMsg::RebalanceStatus`].
+#[cw_serde]
+pub struct RebalanceStatusResponse {
+    /// The time of the last rebalance, or `None` if the vault has never been
+    /// rebalanced.
+    pub last_rebalance: Option<Timestamp>,
+    /// The current target weights of the positions held by the vault.
+    pub target_weights: Vec<TargetWeight>,
+    /// The current weights of the positions held by the vault.
+    pub current_weights: Vec<TargetWeight>,
+    /// The largest absolute difference between the current weight and the
+    /// target weight of any position held by the vault.
+    pub drift: Decimal,
 }
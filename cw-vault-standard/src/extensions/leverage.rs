@@ -0,0 +1,66 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, Decimal, StdResult, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Leverage
+/// extension.
+#[cw_serde]
+pub enum LeverageExecuteMsg {
+    /// Callable by vault admin to update the leverage the vault aims to
+    /// maintain. Fields that are not set are left unchanged.
+    UpdateLeverageTargets {
+        /// The new target leverage.
+        target_leverage: Option<Decimal>,
+        /// The new maximum leverage. The vault should deleverage when its
+        /// leverage exceeds this value.
+        max_leverage: Option<Decimal>,
+    },
+}
+
+impl LeverageExecuteMsg {
+    /// Convert a [`LeverageExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Leverage(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Leverage
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum LeverageQueryMsg {
+    /// Returns [`LeverageInfo`] describing the current leverage and health of
+    /// the vault.
+    #[returns(LeverageInfo)]
+    LeverageInfo {},
+}
+
+/// The leverage and health of a leveraged vault. Leverage is expressed as the
+/// ratio of the vault's gross position value to its net asset value, e.g.
+/// `Decimal::percent(300)` for 3x leverage.
+#[cw_serde]
+pub struct LeverageInfo {
+    /// The current leverage of the vault.
+    pub current_leverage: Decimal,
+    /// The leverage the vault aims to maintain.
+    pub target_leverage: Decimal,
+    /// The maximum leverage of the vault.
+    pub max_leverage: Decimal,
+    /// The loan-to-value ratio at which the vault's position is liquidated.
+    pub liquidation_threshold: Decimal,
+    /// The current health factor of the vault's position. The position is
+    /// liquidatable when this falls below one.
+    pub health_factor: Decimal,
+}
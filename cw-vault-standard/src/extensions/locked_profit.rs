@@ -0,0 +1,29 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Uint128};
+
+/// Additional QueryMsg variants for vaults that enable the LockedProfit
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum LockedProfitQueryMsg {
+    /// Returns [`LockedProfit`] describing the harvested profit that is still
+    /// being released into the total assets of the vault.
+    #[returns(LockedProfit)]
+    LockedProfit {},
+}
+
+/// The locked profit of a vault. Harvested profit is locked and released
+/// linearly over time, so that `TotalAssets` ramps up after a harvest instead
+/// of jumping, which protects the vault against sandwich attacks around
+/// harvests. All amounts are denominated in base tokens.
+#[cw_serde]
+pub struct LockedProfit {
+    /// The amount of harvested profit that is currently locked.
+    pub locked_profit: Uint128,
+    /// The fraction of the profit locked at the last harvest that is released
+    /// per second.
+    pub degradation_rate: Decimal,
+    /// The total assets of the vault excluding the locked profit. This is the
+    /// amount used for converting between base tokens and vault tokens.
+    pub free_assets: Uint128,
+}
@@ -1,9 +1,13 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
 use cw_utils::{Duration, Expiration};
 
 use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
 
+/// The name vaults implementing the Lockup extension should include in the
+/// `extensions` field of the `VaultStandardInfoResponse`.
+pub const LOCKUP_EXTENSION_NAME: &str = "lockup";
+
 /// Type for the unlocking position created event emitted on call to `Unlock`.
 pub const UNLOCKING_POSITION_CREATED_EVENT_TYPE: &str = "unlocking_position_created";
 /// Key for the lockup id attribute in the "unlocking position created" event
@@ -18,7 +22,9 @@ pub enum LockupExecuteMsg {
     /// The caller must pass the native vault tokens in the funds field.
     /// Emits an event with type `UNLOCKING_POSITION_CREATED_EVENT_TYPE` with
     /// an attribute with key `UNLOCKING_POSITION_ATTR_KEY` containing an u64
-    /// lockup_id.
+    /// lockup_id. The vault should also set the response data to the JSON
+    /// encoded u64 lockup_id, see
+    /// [`unlock_lockup_id_from_reply`](crate::parse::unlock_lockup_id_from_reply).
     ///
     /// Like Redeem, this takes an amount so that the same API can be used for
     /// CW4626 and native tokens.
@@ -51,10 +57,14 @@ pub enum LockupExecuteMsg {
 
 impl LockupExecuteMsg {
     /// Convert a [`LockupExecuteMsg`] into a [`CosmosMsg`].
-    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
         Ok(WasmMsg::Execute {
             contract_addr,
-            msg: to_binary(&VaultStandardExecuteMsg::VaultExtension(
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
                 ExtensionExecuteMsg::Lockup(self),
             ))?,
             funds,
@@ -0,0 +1,76 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `ReportLoss`.
+pub const LOSS_REPORTED_EVENT_TYPE: &str = "loss_reported";
+/// Key for the attribute in the "loss reported" event containing the u64 id of
+/// the loss.
+pub const LOSS_ID_ATTR_KEY: &str = "loss_id";
+/// Key for the attribute in the "loss reported" event containing the amount of
+/// base tokens lost.
+pub const LOSS_AMOUNT_ATTR_KEY: &str = "amount";
+
+/// Additional ExecuteMsg variants for vaults that enable the Losses
+/// extension.
+#[cw_serde]
+pub enum LossesExecuteMsg {
+    /// Callable by vault admin or a strategy of the vault to report a loss.
+    /// Emits an event with type `LOSS_REPORTED_EVENT_TYPE` with attributes
+    /// with keys `LOSS_ID_ATTR_KEY` and `LOSS_AMOUNT_ATTR_KEY`.
+    ReportLoss {
+        /// The amount of base tokens lost.
+        amount: Uint128,
+        /// An optional human readable description of the loss.
+        description: Option<String>,
+    },
+}
+
+impl LossesExecuteMsg {
+    /// Convert a [`LossesExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Losses(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Losses extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum LossesQueryMsg {
+    /// Returns a `Vec<LossRecord>` containing the losses reported by the
+    /// vault, in the order they were reported.
+    #[returns(Vec<LossRecord>)]
+    Losses {
+        /// Return results only after this loss id
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// A loss reported by a vault.
+#[cw_serde]
+pub struct LossRecord {
+    /// The ID of the loss.
+    pub id: u64,
+    /// The amount of base tokens lost.
+    pub amount: Uint128,
+    /// The address that reported the loss.
+    pub reporter: Addr,
+    /// The time at which the loss was reported.
+    pub reported_at: Timestamp,
+    /// An optional human readable description of the loss.
+    pub description: Option<String>,
+}
@@ -0,0 +1,64 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `Migrate`.
+pub const POSITION_MIGRATED_EVENT_TYPE: &str = "position_migrated";
+/// Key for the attribute in the "position migrated" event containing the
+/// address of the vault the position was migrated to.
+pub const POSITION_MIGRATED_TARGET_VAULT_ATTR_KEY: &str = "target_vault";
+/// Key for the attribute in the "position migrated" event containing the
+/// amount of vault tokens that were redeemed from this vault.
+pub const POSITION_MIGRATED_SHARES_IN_ATTR_KEY: &str = "shares_in";
+/// Key for the attribute in the "position migrated" event containing the
+/// amount of base tokens that were moved to the target vault.
+pub const POSITION_MIGRATED_BASE_TOKEN_AMOUNT_ATTR_KEY: &str = "base_token_amount";
+/// Key for the attribute in the "position migrated" event containing the
+/// amount of vault tokens of the target vault that were received.
+pub const POSITION_MIGRATED_SHARES_OUT_ATTR_KEY: &str = "shares_out";
+
+/// Additional ExecuteMsg variants for vaults that enable the Migration
+/// extension.
+#[cw_serde]
+pub enum MigrationExecuteMsg {
+    /// Called to redeem vault tokens and deposit the withdrawn base tokens
+    /// into another vault adhering to the standard, in one transaction. The
+    /// native vault token must be passed in the funds parameter. The target
+    /// vault must have the same base token as this vault.
+    /// Emits an event with type `POSITION_MIGRATED_EVENT_TYPE` with
+    /// attributes with keys `POSITION_MIGRATED_TARGET_VAULT_ATTR_KEY`,
+    /// `POSITION_MIGRATED_SHARES_IN_ATTR_KEY`,
+    /// `POSITION_MIGRATED_BASE_TOKEN_AMOUNT_ATTR_KEY` and
+    /// `POSITION_MIGRATED_SHARES_OUT_ATTR_KEY`.
+    Migrate {
+        /// The amount of vault tokens to migrate.
+        amount: Uint128,
+        /// The address of the vault to migrate to.
+        target_vault: String,
+        /// The minimum amount of vault tokens of the target vault to receive.
+        /// The migration must fail if fewer vault tokens would be received.
+        min_shares_out: Uint128,
+        /// The optional recipient of the target vault's vault tokens. If not
+        /// set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+}
+
+impl MigrationExecuteMsg {
+    /// Convert a [`MigrationExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Migration(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
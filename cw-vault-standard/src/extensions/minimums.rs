@@ -0,0 +1,87 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdError, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// The message that a `Deposit` or `Redeem` below the minimum amount must fail
+/// with, so that routers can recognize the error. The vault may append
+/// details after a colon, as done by [`below_minimum_error`].
+pub const BELOW_MINIMUM_ERROR: &str = "amount below minimum";
+
+/// Type for the event emitted on call to `SweepDust`.
+pub const DUST_SWEPT_EVENT_TYPE: &str = "dust_swept";
+/// Key for the attribute in the "dust swept" event containing the swept
+/// tokens, formatted as a comma separated list of coins.
+pub const DUST_SWEPT_AMOUNT_ATTR_KEY: &str = "amount";
+
+/// Returns the standard error for a `Deposit` or `Redeem` of `amount` below
+/// the `minimum` amount.
+pub fn below_minimum_error(amount: Uint128, minimum: Uint128) -> StdError {
+    StdError::generic_err(format!("{BELOW_MINIMUM_ERROR}: {amount} < {minimum}"))
+}
+
+/// Additional ExecuteMsg variants for vaults that enable the Minimums
+/// extension.
+///
+/// A `Deposit` of fewer base tokens than `min_deposit`, or a `Redeem` of fewer
+/// vault tokens than `min_redeem`, must fail with an error starting with
+/// [`BELOW_MINIMUM_ERROR`].
+#[cw_serde]
+pub enum MinimumsExecuteMsg {
+    /// Callable by vault admin to update the minimum amounts. Fields that are
+    /// not set are left unchanged.
+    UpdateMinimums {
+        /// The new minimum amount of base tokens per deposit.
+        min_deposit: Option<Uint128>,
+        /// The new minimum amount of vault tokens per redemption.
+        min_redeem: Option<Uint128>,
+    },
+
+    /// Callable by vault admin to sweep dust, i.e. balances too small to be
+    /// accounted for, that have accumulated in the vault.
+    /// Emits an event with type `DUST_SWEPT_EVENT_TYPE` with an attribute with
+    /// key `DUST_SWEPT_AMOUNT_ATTR_KEY`.
+    SweepDust {
+        /// An optional field containing which address should receive the
+        /// swept dust. If not set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+}
+
+impl MinimumsExecuteMsg {
+    /// Convert a [`MinimumsExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Minimums(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Minimums
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum MinimumsQueryMsg {
+    /// Returns [`Minimums`] containing the minimum deposit and redemption
+    /// amounts of the vault.
+    #[returns(Minimums)]
+    Minimums {},
+}
+
+/// The minimum deposit and redemption amounts of a vault.
+#[cw_serde]
+pub struct Minimums {
+    /// The minimum amount of base tokens per deposit.
+    pub min_deposit: Uint128,
+    /// The minimum amount of vault tokens per redemption.
+    pub min_redeem: Uint128,
+}
@@ -41,3 +41,421 @@ pub mod keeper;
 #[cfg(feature = "cw4626")]
 #[cfg_attr(docsrs, doc(cfg(feature = "cw4626")))]
 pub mod cw4626;
+
+/// The fees extension can be used to expose the fees charged by a vault in a
+/// standardized way. This allows aggregators and front-ends to display the
+/// effective yield of a vault without having to reverse-engineer the fees from
+/// the preview queries.
+#[cfg(feature = "fees")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fees")))]
+pub mod fees;
+
+/// The rewards extension can be used by vaults that distribute additional
+/// reward tokens to their depositors, on top of the appreciation of the vault
+/// token. Depositors can query their pending rewards and claim them via the
+/// `ClaimRewards` variant.
+#[cfg(feature = "rewards")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rewards")))]
+pub mod rewards;
+
+/// The pause extension can be used to add a circuit breaker to a vault,
+/// allowing the vault admin to pause deposits and redeems independently of each
+/// other. Monitoring tools and routers can use the `PauseStatus` query to
+/// detect whether a vault is paused.
+#[cfg(feature = "pause")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pause")))]
+pub mod pause;
+
+/// The shutdown extension can be used to permanently shut down a vault. Unlike
+/// the pause extension, shutdown is a one-way action after which the vault only
+/// allows redeeming, while it unwinds its strategy positions. Integrators can
+/// use the `ShutdownStatus` query to detect this state and stop routing new
+/// deposits to the vault.
+#[cfg(feature = "shutdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "shutdown")))]
+pub mod shutdown;
+
+/// The deposit cap extension can be used to limit the total amount of assets a
+/// vault accepts, as well as the amount a single address may deposit.
+/// Front-ends can use the `Utilization` query to show when a vault is full.
+#[cfg(feature = "deposit-cap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "deposit-cap")))]
+pub mod deposit_cap;
+
+/// The allowlist extension can be used to create permissioned vaults, where
+/// only addresses that have been added to the allowlist by the vault admin are
+/// allowed to deposit.
+#[cfg(feature = "allowlist")]
+#[cfg_attr(docsrs, doc(cfg(feature = "allowlist")))]
+pub mod allowlist;
+
+/// The blocklist extension can be used by vaults that need to block certain
+/// addresses, e.g. for compliance reasons. Blocked addresses are not allowed to
+/// deposit into the vault, nor to send or receive vault tokens of vaults
+/// implementing the Cw4626 extension.
+#[cfg(feature = "blocklist")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocklist")))]
+pub mod blocklist;
+
+/// The multi asset extension can be used by vaults that accept deposits of more
+/// than one asset at once, e.g. vaults that provide liquidity to a liquidity
+/// pool.
+#[cfg(feature = "multi-asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "multi-asset")))]
+pub mod multi_asset;
+
+/// The zap extension can be used to let users enter and exit a vault with a
+/// single asset that is not the base token. The vault swaps the asset into the
+/// base token before depositing, or swaps the base tokens into the requested
+/// asset after redeeming.
+#[cfg(feature = "zap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zap")))]
+pub mod zap;
+
+/// The async redeem extension can be used by vaults that can not fulfill
+/// redemptions synchronously, e.g. because the underlying assets need to be
+/// unbonded. Instead of calling the `VaultStandardExecuteMsg::Redeem` variant,
+/// users call `RequestRedeem` and once the vault has fulfilled the request,
+/// claim the base tokens via `ClaimRedeem`. This is similar to the ERC-7540
+/// standard on Ethereum.
+#[cfg(feature = "async-redeem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async-redeem")))]
+pub mod async_redeem;
+
+/// The twap extension can be used to expose a time-weighted average price of
+/// the vault token over a configurable window. This gives lending protocols and
+/// other integrators a valuation of the vault token that is resistant to
+/// manipulation. The extension includes a recommended `TwapCheckpoints` storage
+/// helper for recording the price history.
+#[cfg(feature = "twap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "twap")))]
+pub mod twap;
+
+/// The oracle extension can be used to expose the price of the vault token
+/// together with metadata about when the vault state was last updated and
+/// whether the price is valid. This allows oracle adapters to reject stale
+/// valuations instead of blindly trusting the `ConvertToAssets` query.
+#[cfg(feature = "oracle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oracle")))]
+pub mod oracle;
+
+/// The admin extension can be used to expose the admin and config of a vault in
+/// a standardized way, so that operations tooling can discover them for any
+/// vault adhering to the standard. The shape of the config itself is specific
+/// to each vault and is carried as a JSON encoded `Binary` payload by the
+/// `UpdateConfig` variant and the `Config` query.
+#[cfg(feature = "admin")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin")))]
+pub mod admin;
+
+/// The timelock extension can be used to enforce a delay between when a
+/// sensitive admin operation, such as a fee or strategy change, is scheduled
+/// and when it can be executed. This gives depositors and monitoring tools a
+/// guaranteed window to react before the change takes effect.
+#[cfg(feature = "timelock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "timelock")))]
+pub mod timelock;
+
+/// The migration extension can be used to let users move their position from
+/// one vault to another vault with the same base token in a single transaction,
+/// e.g. when a vault is upgraded to a new strategy.
+#[cfg(feature = "migration")]
+#[cfg_attr(docsrs, doc(cfg(feature = "migration")))]
+pub mod migration;
+
+/// The strategy extension can be used by vaults that allocate their assets to
+/// multiple strategies. It lets the vault admin add and remove strategies and
+/// update their weights, and lets allocators and dashboards query the
+/// strategies and the assets deployed to each of them.
+#[cfg(feature = "strategy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "strategy")))]
+pub mod strategy;
+
+/// The rate limit extension can be used by vaults that throttle deposits and
+/// withdrawals to protect their underlying strategy. The amount of base tokens
+/// that can be deposited and withdrawn is limited per epoch, and the remaining
+/// capacity can be queried via the `RateLimits` query.
+#[cfg(feature = "rate-limit")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rate-limit")))]
+pub mod rate_limit;
+
+/// The referral extension can be used to let front-end integrators earn a share
+/// of the vault's revenue for the deposits they refer, through a standard
+/// mechanism instead of bespoke per-vault agreements.
+#[cfg(feature = "referral")]
+#[cfg_attr(docsrs, doc(cfg(feature = "referral")))]
+pub mod referral;
+
+/// The vesting extension can be used by vaults where deposited vault tokens
+/// vest over time, e.g. as part of an incentive program. Unlike the lockup
+/// extension, which models unbonding of vault tokens that are being redeemed,
+/// vesting positions release vault tokens to their owner, who can claim them
+/// via the `ClaimVested` variant.
+#[cfg(feature = "vesting")]
+#[cfg_attr(docsrs, doc(cfg(feature = "vesting")))]
+pub mod vesting;
+
+/// The staking extension can be used by liquid staking vaults that delegate
+/// their base tokens to a set of validators. It lets the vault admin manage the
+/// validator set and delegation weights, and lets dashboards query the current
+/// delegations and the amount of tokens being unbonded.
+#[cfg(feature = "staking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "staking")))]
+pub mod staking;
+
+/// The insurance extension can be used by vaults that hold an internal
+/// insurance fund, or buffer, of base tokens to cover losses. It exposes the
+/// size and target of the fund as well as whether it is included in the
+/// `TotalAssets` query, so that risk frameworks can account for this first-loss
+/// capital consistently.
+#[cfg(feature = "insurance")]
+#[cfg_attr(docsrs, doc(cfg(feature = "insurance")))]
+pub mod insurance;
+
+/// The losses extension can be used to keep a machine-readable record of the
+/// losses taken by a vault. Credit managers and other integrators can listen
+/// for the `loss_reported` event or query the reported losses to revalue
+/// positions in the vault immediately.
+#[cfg(feature = "losses")]
+#[cfg_attr(docsrs, doc(cfg(feature = "losses")))]
+pub mod losses;
+
+/// The snapshot extension can be used to expose historical vault token balances
+/// and total supply at past block heights. This enables governance and airdrops
+/// weighted by historical positions in the vault. The extension includes a
+/// recommended `ShareSnapshots` storage helper built on
+/// `cw_storage_plus::SnapshotMap`.
+#[cfg(feature = "snapshot")]
+#[cfg_attr(docsrs, doc(cfg(feature = "snapshot")))]
+pub mod snapshot;
+
+/// The flash loan extension can be used to lend out the idle base tokens of a
+/// vault for the duration of a single transaction, in exchange for a fee.
+/// Borrowers must implement the `FlashLoanReceiverMsg` interface, and vaults
+/// implementing the extension should list it in the `VaultStandardInfo` query
+/// so that routers can discover it.
+#[cfg(feature = "flash-loan")]
+#[cfg_attr(docsrs, doc(cfg(feature = "flash-loan")))]
+pub mod flash_loan;
+
+/// The hooks extension can be used to let the vault admin register external
+/// contracts that are called after every deposit and redeem. This enables
+/// points programs, accounting systems and similar integrations without forking
+/// the vault code.
+#[cfg(feature = "hooks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hooks")))]
+pub mod hooks;
+
+/// The locked profit extension can be used by vaults that release harvested
+/// profit gradually over time instead of all at once. It exposes the currently
+/// locked profit, the rate at which it is released and the resulting free
+/// assets, so that integrators understand why `TotalAssets` ramps up after a
+/// harvest.
+#[cfg(feature = "locked-profit")]
+#[cfg_attr(docsrs, doc(cfg(feature = "locked-profit")))]
+pub mod locked_profit;
+
+/// The router extension defines the interface of position router contracts,
+/// that move a position from one vault to another in a single transaction with
+/// slippage control across both legs. Vaults implementing the extension keep a
+/// list of approved routers.
+#[cfg(feature = "router")]
+#[cfg_attr(docsrs, doc(cfg(feature = "router")))]
+pub mod router;
+
+/// The leverage extension can be used by leveraged vaults to expose their
+/// current and target leverage as well as the health of their position, so that
+/// risk dashboards can monitor any leveraged vault adhering to the standard
+/// without custom adapters.
+#[cfg(feature = "leverage")]
+#[cfg_attr(docsrs, doc(cfg(feature = "leverage")))]
+pub mod leverage;
+
+/// The fee split extension can be used to describe how the fees collected by a
+/// vault are split among several recipients, such as a treasury, a strategist
+/// and an insurance fund. The collected fees are distributed via the
+/// permissionless `DistributeFees` variant.
+#[cfg(feature = "fee-split")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fee-split")))]
+pub mod fee_split;
+
+/// The performance extension can be used to expose the recent price history of
+/// the vault token and the trailing APR computed from it over standard windows,
+/// so that aggregators can rank vaults without trusting off-chain APIs. The
+/// extension includes a recommended `PriceHistory` storage helper for recording
+/// the price history.
+#[cfg(feature = "performance")]
+#[cfg_attr(docsrs, doc(cfg(feature = "performance")))]
+pub mod performance;
+
+/// The epoch extension can be used by vaults that settle once per epoch, such
+/// as options vaults or real world asset vaults. It allows integrators to query
+/// the current epoch and its boundaries as well as the profit or loss of past
+/// epochs, so that deposits and exits can be scheduled around epoch boundaries.
+/// The `RollEpoch` message settles the current epoch and starts the next one.
+#[cfg(feature = "epoch")]
+#[cfg_attr(docsrs, doc(cfg(feature = "epoch")))]
+pub mod epoch;
+
+/// The incentives extension allows third parties, such as gauges or bribe
+/// markets, to attach reward budgets to a vault that are distributed to the
+/// vault token holders over a given period. It also includes queries to list
+/// the active incentive programs of a vault. Accrued incentives can be claimed
+/// through the Rewards extension if the vault enables it.
+#[cfg(feature = "incentives")]
+#[cfg_attr(docsrs, doc(cfg(feature = "incentives")))]
+pub mod incentives;
+
+/// The minimums extension can be used by vaults that enforce a minimum deposit
+/// and redemption amount. Deposits and redemptions below the minimum must fail
+/// with a standard error, so that routers can pre-validate amounts using the
+/// `Minimums` query. The extension also includes a message for the vault admin
+/// to sweep accumulated dust.
+#[cfg(feature = "minimums")]
+#[cfg_attr(docsrs, doc(cfg(feature = "minimums")))]
+pub mod minimums;
+
+/// The cooldown extension can be used by vaults that require a cooldown period
+/// after a deposit before the depositor can redeem. It is a lighter alternative
+/// to the lockup extension for vaults that do not need unlocking positions, as
+/// vault tokens are never locked and can be redeemed directly once the cooldown
+/// has passed.
+#[cfg(feature = "cooldown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cooldown")))]
+pub mod cooldown;
+
+/// The attestation extension can be used by permissioned vaults, such as real
+/// world asset vaults, that require depositors to present an attestation, e.g.
+/// a KYC credential id or a zero-knowledge proof hash, that is verified against
+/// a registry contract. It includes a deposit message carrying the attestation
+/// reference and a query for the registry address and required claims.
+#[cfg(feature = "attestation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "attestation")))]
+pub mod attestation;
+
+/// The split redeem extension allows redeeming vault tokens once and routing
+/// the withdrawn base tokens to multiple recipients, e.g. an 80/20 split
+/// between two treasuries, without an extra splitter contract. It includes a
+/// helper function to validate the recipients of a split redemption.
+#[cfg(feature = "split-redeem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "split-redeem")))]
+pub mod split_redeem;
+
+/// The wrapped position extension allows wrapping vault tokens into a cw721
+/// position NFT and unwrapping it again. This enables vault positions to be
+/// used in NFT-based collateral systems and escrow flows. It includes queries
+/// to resolve a position NFT to the vault tokens it represents.
+#[cfg(feature = "wrapped-position")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wrapped-position")))]
+pub mod wrapped_position;
+
+/// The permit extension allows a relayer to submit a deposit that the depositor
+/// authorized off-chain by signing a permit containing the amount, recipient,
+/// nonce and deadline as an ADR-036 arbitrary message, which Cosmos wallets can
+/// sign. The vault pulls the base tokens using an authz send grant or a cw20
+/// allowance given by the depositor once, which the depositor can set up with a
+/// fee grant from the relayer, so the depositor never pays gas. Permits are
+/// bound to the chain id and vault address and protected against replay by a
+/// strictly increasing per-depositor nonce that can be queried from the vault,
+/// and by a deadline.
+#[cfg(feature = "permit")]
+#[cfg_attr(docsrs, doc(cfg(feature = "permit")))]
+pub mod permit;
+
+/// The child vaults extension can be used by fund-of-funds vaults that allocate
+/// their assets into other conforming vaults. It includes messages for the
+/// vault admin to add and remove child vaults, and a query listing the child
+/// vaults together with the held vault tokens and their value in base tokens,
+/// enabling look-through valuation of the vault.
+#[cfg(feature = "child-vaults")]
+#[cfg_attr(docsrs, doc(cfg(feature = "child-vaults")))]
+pub mod child_vaults;
+
+/// The skim extension standardizes how vaults handle tokens sent directly to
+/// the vault address, which would otherwise distort the share price or get
+/// stranded. Such donations are kept out of the total assets until they are
+/// either absorbed into the vault with `Sync` or swept to a recipient with
+/// `Skim`. The extension also includes a query for the unaccounted balances of
+/// the vault.
+#[cfg(feature = "skim")]
+#[cfg_attr(docsrs, doc(cfg(feature = "skim")))]
+pub mod skim;
+
+/// The interest rate extension can be used by money-market style lending vaults
+/// to expose their utilization, borrow and supply rates, and the parameters of
+/// their interest rate model, so that rate aggregators can index them
+/// uniformly.
+#[cfg(feature = "interest-rate")]
+#[cfg_attr(docsrs, doc(cfg(feature = "interest-rate")))]
+pub mod interest_rate;
+
+/// The collateral extension can be used by vaults whose vault token is meant to
+/// be used as collateral in credit systems, such as credit managers. It
+/// consolidates the information risk engines need into a single
+/// `CollateralInfo` query: the recommended liquidation LTV, whether force
+/// unlocking is supported, and the liquidity available for immediate
+/// redemption.
+#[cfg(feature = "collateral")]
+#[cfg_attr(docsrs, doc(cfg(feature = "collateral")))]
+pub mod collateral;
+
+/// The boost extension can be used by vaults that boost the rewards of
+/// depositors who lock governance tokens, similar to vote-escrow boosting. It
+/// exposes the boost token, the maximum multiplier and the current boost of
+/// each depositor, so that UIs can show both the base and the boosted APR.
+#[cfg(feature = "boost")]
+#[cfg_attr(docsrs, doc(cfg(feature = "boost")))]
+pub mod boost;
+
+/// The batch deposit extension can be used by vaults that deploy capital once
+/// per epoch and therefore pool deposits and mint vault tokens at settlement.
+/// It mirrors the async redeem extension on the deposit side: deposits are
+/// queued into a batch, the batch is settled, and the minted vault tokens are
+/// then claimed by the depositors.
+#[cfg(feature = "batch-deposit")]
+#[cfg_attr(docsrs, doc(cfg(feature = "batch-deposit")))]
+pub mod batch_deposit;
+
+/// The fee tiers extension can be used by vaults that charge reduced fees to
+/// large depositors or holders with a certain status. It exposes the fee tiers
+/// of the vault and the effective fees of a specific address, so that
+/// integrators can compute accurate previews for depositors that receive
+/// rebates.
+#[cfg(feature = "fee-tiers")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fee-tiers")))]
+pub mod fee_tiers;
+
+/// The circuit breaker extension can be used by vaults that automatically pause
+/// deposits when the price per share drops by more than a configured percentage
+/// within a time window. It exposes the config and status of the breaker and
+/// defines the event emitted when the breaker trips, so that monitoring systems
+/// and routers can react immediately.
+#[cfg(feature = "circuit-breaker")]
+#[cfg_attr(docsrs, doc(cfg(feature = "circuit-breaker")))]
+pub mod circuit_breaker;
+
+/// The composition extension exposes the underlying holdings of a vault, such
+/// as tokens, LP positions and positions in other vaults, as a tree with values
+/// denominated in base tokens. This enables portfolio dashboards to do
+/// look-through exposure analysis through a standard interface.
+#[cfg(feature = "composition")]
+#[cfg_attr(docsrs, doc(cfg(feature = "composition")))]
+pub mod composition;
+
+/// The sunset extension can be used to announce the end of life of a vault
+/// together with a wind-down schedule and the terms for redemptions after the
+/// sunset. The standardized `vault_sunset_announced` event allows aggregators
+/// to stop routing deposits and alert users automatically. Unlike the shutdown
+/// extension, which takes effect immediately, a sunset is announced in advance.
+#[cfg(feature = "sunset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sunset")))]
+pub mod sunset;
+
+/// The batch preview extension allows previewing deposits and redeems of
+/// several amounts in a single query, e.g. to quote a price curve. Callers
+/// should only use it if the vault lists `batch-preview` in its vault standard
+/// info, as the `VaultContract` preview batch helpers do, and fall back to one
+/// preview query per amount otherwise.
+#[cfg(feature = "batch-preview")]
+#[cfg_attr(docsrs, doc(cfg(feature = "batch-preview")))]
+pub mod batch_preview;
@@ -0,0 +1,64 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the MultiAsset
+/// extension.
+#[cw_serde]
+pub enum MultiAssetExecuteMsg {
+    /// Called to deposit multiple assets into the vault at once. The assets
+    /// must be passed in the funds parameter and must all be part of the
+    /// assets returned by [`MultiAssetQueryMsg::DepositableAssets`].
+    DepositMultiple {
+        /// The assets to deposit.
+        assets: Vec<Coin>,
+        /// The optional recipient of the vault token. If not set, the caller
+        /// address will be used instead.
+        recipient: Option<String>,
+        /// The minimum amount of vault tokens to receive. The deposit must fail
+        /// if fewer vault tokens would be minted.
+        min_shares_out: Option<Uint128>,
+    },
+}
+
+impl MultiAssetExecuteMsg {
+    /// Convert a [`MultiAssetExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::MultiAsset(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the MultiAsset
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum MultiAssetQueryMsg {
+    /// Returns `Uint128` amount of vault tokens that will be returned for the
+    /// passed in `assets`.
+    ///
+    /// Must return as close to and no more than the exact amount of vault
+    /// tokens that would be minted in a `DepositMultiple` call in the same
+    /// transaction.
+    #[returns(Uint128)]
+    PreviewDepositMultiple {
+        /// The assets to preview depositing.
+        assets: Vec<Coin>,
+    },
+
+    /// Returns a `Vec<String>` containing the denoms of the assets that can be
+    /// deposited via `DepositMultiple`.
+    #[returns(Vec<String>)]
+    DepositableAssets {},
+}
@@ -0,0 +1,31 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Timestamp};
+
+/// Additional QueryMsg variants for vaults that enable the Oracle extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum OracleQueryMsg {
+    /// Returns [`PricePerShareResponse`] containing the price of one vault
+    /// token together with information about when the state it is based on
+    /// was last updated.
+    #[returns(PricePerShareResponse)]
+    PricePerShare {},
+}
+
+/// Returned by [`OracleQueryMsg::PricePerShare`].
+#[cw_serde]
+pub struct PricePerShareResponse {
+    /// The price of one vault token, denominated in base tokens.
+    pub price_per_share: Decimal,
+    /// The block time at which the state used to compute the price was last
+    /// updated, e.g. the last compounding of the vault.
+    pub last_updated_time: Timestamp,
+    /// The block height at which the state used to compute the price was last
+    /// updated.
+    pub last_updated_height: u64,
+    /// Whether the vault considers the price valid. Must be false if the
+    /// vault knows the price to be stale or unreliable, e.g. while paused or
+    /// while a strategy is being unwound. Oracle adapters should reject
+    /// prices that are not valid.
+    pub valid: bool,
+}
@@ -0,0 +1,64 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `UpdatePauseStatus`. The event
+/// contains one attribute per field of the new [`PauseStatus`], with the field
+/// name as key.
+pub const PAUSE_STATUS_UPDATED_EVENT_TYPE: &str = "pause_status_updated";
+
+/// Additional ExecuteMsg variants for vaults that enable the Pause extension.
+#[cw_serde]
+pub enum PauseExecuteMsg {
+    /// Callable by vault admin to pause or unpause deposits and redeems.
+    /// Fields that are not set are left unchanged.
+    ///
+    /// While deposits are paused, `Deposit` must fail. While redeems are
+    /// paused, `Redeem` must fail, as well as `Unlock` if the vault implements
+    /// the lockup extension.
+    /// Emits an event with type `PAUSE_STATUS_UPDATED_EVENT_TYPE`.
+    UpdatePauseStatus {
+        /// Whether deposits should be paused.
+        deposits_paused: Option<bool>,
+        /// Whether redeems should be paused.
+        redeems_paused: Option<bool>,
+    },
+}
+
+impl PauseExecuteMsg {
+    /// Convert a [`PauseExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Pause(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Pause extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum PauseQueryMsg {
+    /// Returns [`PauseStatus`] describing whether deposits and redeems are
+    /// currently paused.
+    #[returns(PauseStatus)]
+    PauseStatus {},
+}
+
+/// Whether deposits and redeems are paused.
+#[cw_serde]
+pub struct PauseStatus {
+    /// Whether deposits are paused.
+    pub deposits_paused: bool,
+    /// Whether redeems are paused.
+    pub redeems_paused: bool,
+}
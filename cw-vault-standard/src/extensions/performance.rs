@@ -0,0 +1,281 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Order, SignedDecimal, StdError, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Map};
+
+/// The number of seconds in a year, used to annualize returns.
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+/// The number of seconds in a day.
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+/// Additional QueryMsg variants for vaults that enable the Performance
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum PerformanceQueryMsg {
+    /// Returns a `Vec<PriceCheckpoint>` containing the recorded price per
+    /// share checkpoints, most recent first.
+    #[returns(Vec<PriceCheckpoint>)]
+    PriceCheckpoints {
+        /// Return results only before this time, in seconds
+        start_before: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns [`TrailingAprs`] containing the annualized return of the vault
+    /// token over the standard trailing windows.
+    #[returns(TrailingAprs)]
+    TrailingAprs {},
+}
+
+/// The price of one vault token at a point in time.
+#[cw_serde]
+pub struct PriceCheckpoint {
+    /// The time of the checkpoint.
+    pub time: Timestamp,
+    /// The price of one vault token, denominated in base tokens.
+    pub price_per_share: Decimal,
+}
+
+/// The annualized return of the vault token over trailing windows, not
+/// compounded. A value is `None` if the vault does not have price history
+/// covering the window.
+#[cw_serde]
+pub struct TrailingAprs {
+    /// The APR over the last day.
+    pub apr_1d: Option<SignedDecimal>,
+    /// The APR over the last 7 days.
+    pub apr_7d: Option<SignedDecimal>,
+    /// The APR over the last 30 days.
+    pub apr_30d: Option<SignedDecimal>,
+}
+
+/// Recommended storage helper for vaults implementing the Performance
+/// extension.
+///
+/// The vault should call [`PriceHistory::record`] regularly, e.g. on every
+/// compounding, and can then answer the [`PerformanceQueryMsg`] queries with
+/// [`PriceHistory::checkpoints`] and [`PriceHistory::trailing_aprs`].
+pub struct PriceHistory<'a> {
+    checkpoints: Map<'a, u64, Decimal>,
+}
+
+impl<'a> PriceHistory<'a> {
+    /// Create a new PriceHistory instance storing its checkpoints under
+    /// `namespace`.
+    pub const fn new(namespace: &'a str) -> Self {
+        Self {
+            checkpoints: Map::new(namespace),
+        }
+    }
+
+    /// Record the price per share at the given time.
+    pub fn record(
+        &self,
+        storage: &mut dyn Storage,
+        time: Timestamp,
+        price_per_share: Decimal,
+    ) -> StdResult<()> {
+        self.checkpoints
+            .save(storage, time.seconds(), &price_per_share)
+    }
+
+    /// Returns the recorded checkpoints, most recent first.
+    pub fn checkpoints(
+        &self,
+        storage: &dyn Storage,
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<PriceCheckpoint>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        self.checkpoints
+            .range(
+                storage,
+                None,
+                start_before.map(Bound::exclusive),
+                Order::Descending,
+            )
+            .take(limit)
+            .map(|item| {
+                let (time, price_per_share) = item?;
+                Ok(PriceCheckpoint {
+                    time: Timestamp::from_seconds(time),
+                    price_per_share,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the annualized return over the `window_secs` seconds before
+    /// `time`, or `None` if there is no price history covering the window.
+    /// The return is annualized over the time between the latest checkpoints
+    /// at or before the start and the end of the window, so a stale end
+    /// checkpoint does not dilute the APR.
+    pub fn trailing_apr(
+        &self,
+        storage: &dyn Storage,
+        time: Timestamp,
+        window_secs: u64,
+    ) -> StdResult<Option<SignedDecimal>> {
+        let end = time.seconds();
+        let Some(start) = end.checked_sub(window_secs) else {
+            return Ok(None);
+        };
+        let (Some((start_ts, start_price)), Some((end_ts, end_price))) = (
+            self.last_at_or_before(storage, start)?,
+            self.last_at_or_before(storage, end)?,
+        ) else {
+            return Ok(None);
+        };
+        if end_ts == start_ts || start_price.is_zero() {
+            return Ok(None);
+        }
+
+        let to_signed = |d: Decimal| {
+            SignedDecimal::try_from(d).map_err(|e| StdError::generic_err(e.to_string()))
+        };
+        let growth = to_signed(end_price)?
+            .checked_div(to_signed(start_price)?)
+            .map_err(|e| StdError::generic_err(e.to_string()))?
+            .checked_sub(SignedDecimal::one())?;
+        let annualization = SignedDecimal::checked_from_ratio(
+            SECONDS_PER_YEAR as i128,
+            (end_ts - start_ts) as i128,
+        )
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+        Ok(Some(growth.checked_mul(annualization)?))
+    }
+
+    /// Returns the annualized return over the standard trailing windows
+    /// before `time`.
+    pub fn trailing_aprs(&self, storage: &dyn Storage, time: Timestamp) -> StdResult<TrailingAprs> {
+        Ok(TrailingAprs {
+            apr_1d: self.trailing_apr(storage, time, SECONDS_PER_DAY)?,
+            apr_7d: self.trailing_apr(storage, time, 7 * SECONDS_PER_DAY)?,
+            apr_30d: self.trailing_apr(storage, time, 30 * SECONDS_PER_DAY)?,
+        })
+    }
+
+    /// Returns the latest checkpoint at or before `time` (in seconds).
+    fn last_at_or_before(
+        &self,
+        storage: &dyn Storage,
+        time: u64,
+    ) -> StdResult<Option<(u64, Decimal)>> {
+        self.checkpoints
+            .range(
+                storage,
+                None,
+                Some(Bound::inclusive(time)),
+                Order::Descending,
+            )
+            .next()
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    const HISTORY: PriceHistory = PriceHistory::new("price_history");
+
+    fn record(storage: &mut dyn Storage, day: u64, price: &str) {
+        HISTORY
+            .record(
+                storage,
+                Timestamp::from_seconds(day * SECONDS_PER_DAY),
+                price.parse().unwrap(),
+            )
+            .unwrap();
+    }
+
+    fn trailing_apr(storage: &dyn Storage, day: u64, window_days: u64) -> Option<SignedDecimal> {
+        HISTORY
+            .trailing_apr(
+                storage,
+                Timestamp::from_seconds(day * SECONDS_PER_DAY),
+                window_days * SECONDS_PER_DAY,
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn annualizes_return_over_window() {
+        let mut storage = MockStorage::new();
+        record(&mut storage, 0, "1");
+        record(&mut storage, 73, "1.1");
+
+        // 10% over 73 days, which is a fifth of a year.
+        assert_eq!(
+            trailing_apr(&storage, 73, 73),
+            Some(SignedDecimal::percent(50))
+        );
+    }
+
+    #[test]
+    fn returns_negative_apr_on_loss() {
+        let mut storage = MockStorage::new();
+        record(&mut storage, 0, "1");
+        record(&mut storage, 1, "0.99");
+
+        assert_eq!(
+            trailing_apr(&storage, 1, 1),
+            Some(SignedDecimal::percent(-365))
+        );
+    }
+
+    #[test]
+    fn returns_none_for_window_before_first_checkpoint() {
+        let mut storage = MockStorage::new();
+        record(&mut storage, 10, "1");
+        record(&mut storage, 11, "1.01");
+
+        assert_eq!(trailing_apr(&storage, 11, 7), None);
+        // The window can not start before the chain history either.
+        assert_eq!(trailing_apr(&storage, 11, 30), None);
+    }
+
+    #[test]
+    fn annualizes_over_checkpoint_interval_when_end_is_stale() {
+        let mut storage = MockStorage::new();
+        record(&mut storage, 0, "1");
+        record(&mut storage, 1, "1.01");
+
+        // The latest checkpoint is two days old, so the return is annualized
+        // over the day between the checkpoints, not the three day window.
+        assert_eq!(
+            trailing_apr(&storage, 3, 3),
+            Some(SignedDecimal::percent(365))
+        );
+        // Both ends of the window resolve to the same checkpoint.
+        assert_eq!(trailing_apr(&storage, 10, 1), None);
+    }
+
+    #[test]
+    fn computes_standard_trailing_windows() {
+        let mut storage = MockStorage::new();
+        record(&mut storage, 0, "1");
+        record(&mut storage, 23, "1");
+        record(&mut storage, 29, "1");
+        record(&mut storage, 30, "1.01");
+
+        let aprs = HISTORY
+            .trailing_aprs(&storage, Timestamp::from_seconds(30 * SECONDS_PER_DAY))
+            .unwrap();
+        assert_eq!(
+            aprs,
+            TrailingAprs {
+                apr_1d: Some(SignedDecimal::percent(365)),
+                apr_7d: Some(SignedDecimal::from_ratio(365, 700)),
+                apr_30d: Some(SignedDecimal::from_ratio(365, 3000)),
+            }
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, to_json_vec, Binary, Coin, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg,
+};
+use serde::Serialize;
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `DepositWithPermit`.
+pub const PERMIT_USED_EVENT_TYPE: &str = "permit_used";
+/// Key for the attribute in the "permit used" event containing the address of
+/// the depositor that signed the permit.
+pub const PERMIT_DEPOSITOR_ATTR_KEY: &str = "depositor";
+/// Key for the attribute in the "permit used" event containing the u64 nonce
+/// of the permit.
+pub const PERMIT_NONCE_ATTR_KEY: &str = "nonce";
+
+/// Additional ExecuteMsg variants for vaults that enable the Permit
+/// extension.
+#[cw_serde]
+pub enum PermitExecuteMsg {
+    /// Deposit base tokens on behalf of a depositor that authorized the
+    /// deposit by signing a [`DepositPermit`] with their wallet. Callable by
+    /// anyone, e.g. a relayer sponsoring the transaction fees of the
+    /// depositor.
+    ///
+    /// The vault pulls `permit.amount` base tokens from the depositor, so the
+    /// caller never holds them:
+    /// - for a native base token, by dispatching an authz `MsgExec` wrapping a
+    ///   bank `MsgSend` from the depositor to the vault, using a
+    ///   `SendAuthorization` the depositor granted to the vault,
+    /// - for a cw20 base token, with `Cw20ExecuteMsg::TransferFrom`, using an
+    ///   allowance the depositor granted to the vault.
+    ///
+    /// The grant or allowance is a one-time on-chain setup that can cover any
+    /// number of permits. The depositor can submit it using a fee grant from
+    /// the relayer, so that the depositor never needs to hold gas tokens.
+    ///
+    /// The vault must fail if:
+    /// - any funds are sent with the message,
+    /// - `pub_key` does not derive to `permit.depositor`,
+    /// - `signature` is not a valid secp256k1 signature by `pub_key` of the
+    ///   SHA-256 hash of [`DepositPermit::sign_bytes`],
+    /// - `permit.vault` is not the address of the vault or `permit.chain_id` is
+    ///   not the chain id of the current chain,
+    /// - `permit.nonce` is not strictly greater than the last permit nonce of
+    ///   the depositor, as returned by [`PermitQueryMsg::PermitNonce`],
+    /// - the block time is not before `permit.deadline`.
+    ///
+    /// On success the last permit nonce of the depositor is set to
+    /// `permit.nonce`, so that neither this permit nor any earlier one can be
+    /// used again.
+    /// Emits an event with type `PERMIT_USED_EVENT_TYPE` with attributes with
+    /// keys `PERMIT_DEPOSITOR_ATTR_KEY` and `PERMIT_NONCE_ATTR_KEY`.
+    DepositWithPermit {
+        /// The deposit authorized by the depositor.
+        permit: DepositPermit,
+        /// The secp256k1 public key of the depositor.
+        pub_key: Binary,
+        /// The signature of the depositor over the permit.
+        signature: Binary,
+    },
+
+    /// Set the last permit nonce of the caller to `nonce`, invalidating any
+    /// permit signed by the caller with a nonce up to and including `nonce`
+    /// that has not yet been used. Must fail if `nonce` is not strictly
+    /// greater than the current last permit nonce of the caller.
+    InvalidatePermits {
+        /// The new last permit nonce of the caller.
+        nonce: u64,
+    },
+}
+
+impl PermitExecuteMsg {
+    /// Convert a [`PermitExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Permit(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Permit extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum PermitQueryMsg {
+    /// Returns the last u64 permit nonce used or invalidated by the given
+    /// address, or zero if there is none. The next permit signed by the
+    /// address must use a greater nonce.
+    #[returns(u64)]
+    PermitNonce {
+        /// The address of the depositor.
+        address: String,
+    },
+}
+
+/// A deposit authorized off-chain by the depositor.
+#[cw_serde]
+pub struct DepositPermit {
+    /// The chain id of the chain the vault is deployed on. The ADR-036 sign
+    /// doc has an empty chain id, so the permit must carry it to prevent
+    /// replay on other chains.
+    pub chain_id: String,
+    /// The address of the vault.
+    pub vault: String,
+    /// The address of the depositor that signs the permit.
+    pub depositor: String,
+    /// The amount of base tokens to pull from the depositor and deposit.
+    pub amount: Uint128,
+    /// The recipient of the vault tokens.
+    pub recipient: String,
+    /// The permit nonce, which must be greater than the last permit nonce of
+    /// the depositor.
+    pub nonce: u64,
+    /// The time from which the permit can no longer be used.
+    pub deadline: Timestamp,
+}
+
+impl DepositPermit {
+    /// Returns the bytes that the depositor signs. These are the bytes of the
+    /// [ADR-036](https://github.com/cosmos/cosmos-sdk/blob/main/docs/architecture/adr-036-arbitrary-signature.md)
+    /// sign doc, as produced by wallets like Keplr for `signArbitrary`, with
+    /// `permit.depositor` as signer and the JSON serialization of the permit
+    /// as data.
+    pub fn sign_bytes(&self) -> StdResult<Vec<u8>> {
+        to_json_vec(&Adr036SignDoc {
+            account_number: "0",
+            chain_id: "",
+            fee: Adr036Fee {
+                amount: vec![],
+                gas: "0",
+            },
+            memo: "",
+            msgs: [Adr036Msg {
+                msg_type: "sign/MsgSignData",
+                value: MsgSignData {
+                    data: to_json_binary(self)?,
+                    signer: &self.depositor,
+                },
+            }],
+            sequence: "0",
+        })
+    }
+}
+
+/// The amino JSON sign doc of an ADR-036 arbitrary message. The fields are
+/// declared in alphabetical order, as amino JSON requires sorted keys.
+#[derive(Serialize)]
+struct Adr036SignDoc<'a> {
+    account_number: &'a str,
+    chain_id: &'a str,
+    fee: Adr036Fee<'a>,
+    memo: &'a str,
+    msgs: [Adr036Msg<'a>; 1],
+    sequence: &'a str,
+}
+
+#[derive(Serialize)]
+struct Adr036Fee<'a> {
+    amount: Vec<Coin>,
+    gas: &'a str,
+}
+
+#[derive(Serialize)]
+struct Adr036Msg<'a> {
+    #[serde(rename = "type")]
+    msg_type: &'a str,
+    value: MsgSignData<'a>,
+}
+
+#[derive(Serialize)]
+struct MsgSignData<'a> {
+    data: Binary,
+    signer: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+
+    use super::*;
+
+    #[test]
+    fn sign_bytes_are_adr036_sign_doc() {
+        let permit = DepositPermit {
+            chain_id: "osmosis-1".to_string(),
+            vault: "vault".to_string(),
+            depositor: "osmo1depositor".to_string(),
+            amount: Uint128::new(100),
+            recipient: "osmo1recipient".to_string(),
+            nonce: 1,
+            deadline: Timestamp::from_seconds(1000),
+        };
+        let data = to_json_binary(&permit).unwrap();
+
+        let sign_bytes = String::from_utf8(permit.sign_bytes().unwrap()).unwrap();
+        assert_eq!(
+            sign_bytes,
+            format!(
+                r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{data}","signer":"osmo1depositor"}}}}],"sequence":"0"}}"#
+            )
+        );
+        assert_eq!(from_json::<DepositPermit>(&data).unwrap(), permit);
+    }
+}
@@ -0,0 +1,76 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+use cw_utils::{Duration, Expiration};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the RateLimit
+/// extension.
+#[cw_serde]
+pub enum RateLimitExecuteMsg {
+    /// Callable by vault admin to update the rate limits. Fields that are not
+    /// set are left unchanged.
+    UpdateRateLimits {
+        /// The new length of an epoch.
+        epoch_duration: Option<Duration>,
+        /// The new maximum amount of base tokens that may be deposited per
+        /// epoch.
+        deposit_limit: Option<Uint128>,
+        /// The new maximum amount of base tokens that may be withdrawn per
+        /// epoch.
+        withdrawal_limit: Option<Uint128>,
+    },
+}
+
+impl RateLimitExecuteMsg {
+    /// Convert a [`RateLimitExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::RateLimit(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the RateLimit
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum RateLimitQueryMsg {
+    /// Returns [`RateLimits`] containing the rate limits of the vault and the
+    /// remaining capacity in the current epoch.
+    #[returns(RateLimits)]
+    RateLimits {},
+}
+
+/// The rate limits of a vault. All amounts are denominated in base tokens.
+///
+/// A `Deposit` must fail if it would exceed `remaining_deposit`, and a
+/// `Redeem` must fail if the withdrawn base tokens would exceed
+/// `remaining_withdrawal`.
+#[cw_serde]
+pub struct RateLimits {
+    /// The length of an epoch.
+    pub epoch_duration: Duration,
+    /// The maximum amount of base tokens that may be deposited per epoch.
+    pub deposit_limit: Uint128,
+    /// The maximum amount of base tokens that may be withdrawn per epoch.
+    pub withdrawal_limit: Uint128,
+    /// The amount of base tokens that may still be deposited in the current
+    /// epoch.
+    pub remaining_deposit: Uint128,
+    /// The amount of base tokens that may still be withdrawn in the current
+    /// epoch.
+    pub remaining_withdrawal: Uint128,
+    /// A `cw_utils::Expiration` containing information about when the current
+    /// epoch ends and the remaining capacity is reset.
+    pub epoch_end: Expiration,
+}
@@ -0,0 +1,86 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `DepositWithReferral`.
+pub const REFERRAL_DEPOSIT_EVENT_TYPE: &str = "referral_deposit";
+/// Key for the attribute in the "referral deposit" event containing the
+/// referral code.
+pub const REFERRAL_CODE_ATTR_KEY: &str = "referral_code";
+
+/// Additional ExecuteMsg variants for vaults that enable the Referral
+/// extension.
+#[cw_serde]
+pub enum ReferralExecuteMsg {
+    /// Register a referral code for the caller. Deposits made with this code
+    /// will accrue referral earnings to the caller. Must fail if the code is
+    /// already registered.
+    RegisterReferralCode {
+        /// The referral code to register.
+        code: String,
+    },
+
+    /// Called to deposit into the vault with a referral code. Behaves exactly
+    /// like `VaultStandardExecuteMsg::Deposit`, except that referral earnings
+    /// are accrued to the owner of the referral code. Must fail if the code
+    /// is not registered.
+    /// Emits an event with type `REFERRAL_DEPOSIT_EVENT_TYPE` with an
+    /// attribute with key `REFERRAL_CODE_ATTR_KEY`.
+    DepositWithReferral {
+        /// The amount of base tokens to deposit.
+        amount: Uint128,
+        /// The optional recipient of the vault token. If not set, the caller
+        /// address will be used instead.
+        recipient: Option<String>,
+        /// The referral code.
+        referral_code: String,
+    },
+
+    /// Claim the referral earnings of the caller.
+    ClaimReferralEarnings {
+        /// An optional field containing which address should receive the
+        /// earnings. If not set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+}
+
+impl ReferralExecuteMsg {
+    /// Convert a [`ReferralExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Referral(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Referral
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ReferralQueryMsg {
+    /// Returns `Option<Addr>` containing the owner of the referral code, if
+    /// it is registered.
+    #[returns(Option<Addr>)]
+    ReferralCodeOwner {
+        /// The referral code.
+        code: String,
+    },
+
+    /// Returns a `Vec<Coin>` containing the unclaimed referral earnings of
+    /// `referrer`.
+    #[returns(Vec<Coin>)]
+    ReferralEarnings {
+        /// The address of the referrer.
+        referrer: String,
+    },
+}
@@ -0,0 +1,105 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Coin, CosmosMsg, Decimal, StdResult, Timestamp, Uint128, WasmMsg,
+};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `ClaimRewards`.
+pub const REWARDS_CLAIMED_EVENT_TYPE: &str = "rewards_claimed";
+/// Key for the attribute in the "rewards claimed" event containing the address
+/// that the rewards were claimed for.
+pub const REWARDS_CLAIMED_OWNER_ATTR_KEY: &str = "owner";
+/// Key for the attribute in the "rewards claimed" event containing the address
+/// that received the rewards.
+pub const REWARDS_CLAIMED_RECIPIENT_ATTR_KEY: &str = "recipient";
+/// Key for the attribute in the "rewards claimed" event containing the claimed
+/// rewards, formatted as a comma separated list of coins, e.g.
+/// `100uosmo,50uion`.
+pub const REWARDS_CLAIMED_AMOUNT_ATTR_KEY: &str = "amount";
+
+/// Additional ExecuteMsg variants for vaults that enable the Rewards
+/// extension.
+#[cw_serde]
+pub enum RewardsExecuteMsg {
+    /// Claim all pending rewards of the caller.
+    /// Emits an event with type `REWARDS_CLAIMED_EVENT_TYPE` with attributes
+    /// with keys `REWARDS_CLAIMED_OWNER_ATTR_KEY`,
+    /// `REWARDS_CLAIMED_RECIPIENT_ATTR_KEY` and
+    /// `REWARDS_CLAIMED_AMOUNT_ATTR_KEY`.
+    ClaimRewards {
+        /// An optional field containing which address should receive the
+        /// claimed rewards. If not set, the caller address will be used
+        /// instead.
+        recipient: Option<String>,
+    },
+
+    /// Set whether the rewards of the caller should be automatically
+    /// compounded into the vault instead of being claimable.
+    SetAutoCompound {
+        /// Whether to enable auto-compounding for the caller.
+        enabled: bool,
+    },
+}
+
+impl RewardsExecuteMsg {
+    /// Convert a [`RewardsExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Rewards(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Rewards extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum RewardsQueryMsg {
+    /// Returns a `Vec<Coin>` containing the rewards that `owner` can currently
+    /// claim.
+    #[returns(Vec<Coin>)]
+    PendingRewards {
+        /// The address to query pending rewards for
+        owner: String,
+    },
+
+    /// Returns a `Vec<RewardSchedule>` containing the emission schedule of
+    /// each reward token distributed by the vault.
+    #[returns(Vec<RewardSchedule>)]
+    RewardSchedules {},
+
+    /// Returns bool, whether auto-compounding of rewards is enabled for
+    /// `owner` or not.
+    #[returns(bool)]
+    AutoCompound {
+        /// The address to query the auto-compound setting for
+        owner: String,
+    },
+}
+
+/// The emission schedule of a reward token distributed by a vault.
+#[cw_serde]
+pub struct RewardSchedule {
+    /// The denom of the reward token if it is a native token and the contract
+    /// address if it is a cw20 token.
+    pub denom: String,
+    /// The amount of reward tokens emitted per second, shared among all
+    /// depositors.
+    pub emission_per_second: Decimal,
+    /// The time at which the emission starts.
+    pub start: Timestamp,
+    /// The time at which the emission ends, or `None` if the emission has no
+    /// predefined end.
+    pub end: Option<Timestamp>,
+    /// The amount of reward tokens that remain to be emitted.
+    pub remaining: Uint128,
+}
@@ -0,0 +1,117 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted by a position router when it exits the source
+/// vault.
+pub const ROUTE_EXIT_EVENT_TYPE: &str = "route_exit";
+/// Type for the event emitted by a position router when it enters the target
+/// vault.
+pub const ROUTE_ENTER_EVENT_TYPE: &str = "route_enter";
+/// Key for the attribute in the "route exit" and "route enter" events
+/// containing the u64 id of the route, tying the two legs together.
+pub const ROUTE_ID_ATTR_KEY: &str = "route_id";
+/// Key for the attribute in the "route exit" and "route enter" events
+/// containing the address of the vault of the leg.
+pub const ROUTE_VAULT_ATTR_KEY: &str = "vault";
+/// Key for the attribute in the "route exit" and "route enter" events
+/// containing the amount of vault tokens redeemed or received.
+pub const ROUTE_SHARES_ATTR_KEY: &str = "shares";
+/// Key for the attribute in the "route exit" and "route enter" events
+/// containing the amount of base tokens withdrawn or deposited.
+pub const ROUTE_BASE_TOKEN_AMOUNT_ATTR_KEY: &str = "base_token_amount";
+
+/// Additional ExecuteMsg variants for vaults that enable the Router
+/// extension.
+#[cw_serde]
+pub enum RouterExecuteMsg {
+    /// Callable by vault admin to update the list of approved position
+    /// routers. Approved routers may be exempt from restrictions that apply
+    /// to regular users, e.g. deposit caps or cooldowns.
+    UpdateApprovedRouters {
+        /// Addresses to add to the list of approved routers.
+        add_addresses: Vec<String>,
+        /// Addresses to remove from the list of approved routers.
+        remove_addresses: Vec<String>,
+    },
+}
+
+impl RouterExecuteMsg {
+    /// Convert a [`RouterExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Router(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Router extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum RouterQueryMsg {
+    /// Returns a `Vec<Addr>` containing the approved position routers.
+    #[returns(Vec<Addr>)]
+    ApprovedRouters {
+        /// Return results only after this address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// The ExecuteMsg that position router contracts must implement.
+#[cw_serde]
+pub enum PositionRouterExecuteMsg {
+    /// Move a position from one vault to another vault in one transaction by
+    /// redeeming vault tokens of `from_vault`, converting the withdrawn base
+    /// tokens if needed, and depositing into `to_vault`.
+    ///
+    /// If `from_vault` uses a native vault token, the vault tokens must be
+    /// passed in the funds parameter. If it implements the Cw4626 extension,
+    /// the router pulls the vault tokens from the caller using an allowance.
+    ///
+    /// Emits an event with type `ROUTE_EXIT_EVENT_TYPE` and an event with type
+    /// `ROUTE_ENTER_EVENT_TYPE`, both with an attribute with key
+    /// `ROUTE_ID_ATTR_KEY` containing the same u64 route_id.
+    RoutePosition {
+        /// The address of the vault to exit.
+        from_vault: String,
+        /// The address of the vault to enter.
+        to_vault: String,
+        /// The amount of vault tokens of `from_vault` to move.
+        amount: Uint128,
+        /// The minimum amount of vault tokens of `to_vault` to receive,
+        /// covering the slippage of both legs. The route must fail if fewer
+        /// vault tokens would be received.
+        min_shares_out: Uint128,
+        /// The optional recipient of the vault tokens of `to_vault`. If not
+        /// set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+}
+
+impl PositionRouterExecuteMsg {
+    /// Convert a [`PositionRouterExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&self)?,
+            funds,
+        }
+        .into())
+    }
+}
@@ -0,0 +1,74 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `InitiateShutdown`.
+pub const SHUTDOWN_INITIATED_EVENT_TYPE: &str = "shutdown_initiated";
+
+/// Additional ExecuteMsg variants for vaults that enable the Shutdown
+/// extension.
+#[cw_serde]
+pub enum ShutdownExecuteMsg {
+    /// Callable by vault admin to permanently shut down the vault. This action
+    /// can not be undone.
+    ///
+    /// After this has been called, `Deposit` must always fail, while `Redeem`
+    /// must keep working so that depositors can exit the vault. The vault
+    /// should unwind its strategy positions into base tokens, which may
+    /// happen over several transactions.
+    /// Emits an event with type `SHUTDOWN_INITIATED_EVENT_TYPE`.
+    InitiateShutdown {},
+}
+
+impl ShutdownExecuteMsg {
+    /// Convert a [`ShutdownExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Shutdown(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Shutdown extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ShutdownQueryMsg {
+    /// Returns [`ShutdownStatus`] describing whether the vault has been shut
+    /// down and how far the unwinding of its positions has progressed.
+    #[returns(ShutdownStatus)]
+    ShutdownStatus {},
+}
+
+/// The lifecycle state of a vault with respect to shutdown.
+#[cw_serde]
+pub enum ShutdownState {
+    /// The vault is operating normally.
+    Active,
+    /// Shutdown has been initiated and the vault is unwinding its positions.
+    Unwinding,
+    /// Shutdown has been initiated and all positions have been unwound.
+    Completed,
+}
+
+/// Information about the shutdown of a vault.
+#[cw_serde]
+pub struct ShutdownStatus {
+    /// The current shutdown state of the vault.
+    pub state: ShutdownState,
+    /// The time at which shutdown was initiated, or `None` if the vault is
+    /// still active.
+    pub initiated_at: Option<Timestamp>,
+    /// The amount of assets, denominated in base tokens, that are still held
+    /// in strategy positions and have not yet been unwound.
+    pub assets_remaining: Uint128,
+}
@@ -0,0 +1,64 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `Sync`.
+pub const SYNCED_EVENT_TYPE: &str = "synced";
+/// Type for the event emitted on call to `Skim`.
+pub const SKIMMED_EVENT_TYPE: &str = "skimmed";
+/// Key for the attribute in the "synced" and "skimmed" events containing the
+/// absorbed or swept tokens, formatted as a comma separated list of coins.
+pub const SKIM_AMOUNT_ATTR_KEY: &str = "amount";
+
+/// Additional ExecuteMsg variants for vaults that enable the Skim extension.
+///
+/// Tokens sent directly to the vault address, outside of `Deposit` or any
+/// other message of the vault, are unaccounted for and must not be included
+/// in `TotalAssets` until they are absorbed with `Sync` or swept with `Skim`.
+#[cw_serde]
+pub enum SkimExecuteMsg {
+    /// Absorb the unaccounted base tokens held by the vault into the total
+    /// assets of the vault, distributing them to the vault token holders.
+    /// Callable by anyone.
+    /// Emits an event with type `SYNCED_EVENT_TYPE` with an attribute with
+    /// key `SKIM_AMOUNT_ATTR_KEY`.
+    Sync {},
+
+    /// Callable by vault admin to send all unaccounted tokens held by the
+    /// vault to a recipient, e.g. to return funds sent by mistake.
+    /// Emits an event with type `SKIMMED_EVENT_TYPE` with an attribute with
+    /// key `SKIM_AMOUNT_ATTR_KEY`.
+    Skim {
+        /// The address that receives the unaccounted tokens.
+        recipient: String,
+    },
+}
+
+impl SkimExecuteMsg {
+    /// Convert a [`SkimExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Skim(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Skim extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum SkimQueryMsg {
+    /// Returns a `Vec<Coin>` containing the tokens held by the vault that are
+    /// not accounted for.
+    #[returns(Vec<Coin>)]
+    UnaccountedBalances {},
+}
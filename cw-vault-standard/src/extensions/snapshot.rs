@@ -0,0 +1,186 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
+use cw_storage_plus::{SnapshotItem, SnapshotMap, Strategy};
+
+/// Additional QueryMsg variants for vaults that enable the Snapshot
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum SnapshotQueryMsg {
+    /// Returns `Uint128` vault token balance of `address` at the start of the
+    /// block at `height`.
+    #[returns(Uint128)]
+    BalanceAt {
+        /// The address to query the balance of
+        address: String,
+        /// The block height to query the balance at
+        height: u64,
+    },
+
+    /// Returns `Uint128` total amount of vault tokens in circulation at the
+    /// start of the block at `height`.
+    #[returns(Uint128)]
+    TotalSupplyAt {
+        /// The block height to query the total supply at
+        height: u64,
+    },
+}
+
+/// Recommended storage helper for vaults implementing the Snapshot extension.
+///
+/// The vault should call [`ShareSnapshots::save_balance`] and
+/// [`ShareSnapshots::save_total_supply`] whenever a balance or the total
+/// supply of vault tokens changes, and can then answer the
+/// [`SnapshotQueryMsg`] queries with [`ShareSnapshots::balance_at`] and
+/// [`ShareSnapshots::total_supply_at`].
+pub struct ShareSnapshots<'a> {
+    balances: SnapshotMap<'a, &'a Addr, Uint128>,
+    total_supply: SnapshotItem<'a, Uint128>,
+}
+
+impl<'a> ShareSnapshots<'a> {
+    /// Create a new ShareSnapshots instance using the default namespaces.
+    pub const fn new() -> Self {
+        Self::with_namespaces(
+            "snapshot_balances",
+            "snapshot_balances__checkpoints",
+            "snapshot_balances__changelog",
+            "snapshot_total_supply",
+            "snapshot_total_supply__checkpoints",
+            "snapshot_total_supply__changelog",
+        )
+    }
+
+    /// Create a new ShareSnapshots instance using custom namespaces.
+    pub const fn with_namespaces(
+        balances: &'a str,
+        balances_checkpoints: &'a str,
+        balances_changelog: &'a str,
+        total_supply: &'a str,
+        total_supply_checkpoints: &'a str,
+        total_supply_changelog: &'a str,
+    ) -> Self {
+        Self {
+            balances: SnapshotMap::new(
+                balances,
+                balances_checkpoints,
+                balances_changelog,
+                Strategy::EveryBlock,
+            ),
+            total_supply: SnapshotItem::new(
+                total_supply,
+                total_supply_checkpoints,
+                total_supply_changelog,
+                Strategy::EveryBlock,
+            ),
+        }
+    }
+
+    /// Save the new vault token balance of `address` at the current block
+    /// `height`.
+    pub fn save_balance(
+        &self,
+        storage: &mut dyn Storage,
+        address: &'a Addr,
+        balance: Uint128,
+        height: u64,
+    ) -> StdResult<()> {
+        self.balances.save(storage, address, &balance, height)
+    }
+
+    /// Save the new total supply of vault tokens at the current block
+    /// `height`.
+    pub fn save_total_supply(
+        &self,
+        storage: &mut dyn Storage,
+        total_supply: Uint128,
+        height: u64,
+    ) -> StdResult<()> {
+        self.total_supply.save(storage, &total_supply, height)
+    }
+
+    /// Returns the vault token balance of `address` at the start of the block
+    /// at `height`.
+    pub fn balance_at(
+        &self,
+        storage: &dyn Storage,
+        address: &'a Addr,
+        height: u64,
+    ) -> StdResult<Uint128> {
+        Ok(self
+            .balances
+            .may_load_at_height(storage, address, height)?
+            .unwrap_or_default())
+    }
+
+    /// Returns the total supply of vault tokens at the start of the block at
+    /// `height`.
+    pub fn total_supply_at(&self, storage: &dyn Storage, height: u64) -> StdResult<Uint128> {
+        Ok(self
+            .total_supply
+            .may_load_at_height(storage, height)?
+            .unwrap_or_default())
+    }
+}
+
+impl Default for ShareSnapshots<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    #[test]
+    fn balance_at_returns_balance_at_start_of_block() {
+        let snapshots = ShareSnapshots::new();
+        let mut storage = MockStorage::new();
+        let alice = Addr::unchecked("alice");
+        let bob = Addr::unchecked("bob");
+
+        snapshots
+            .save_balance(&mut storage, &alice, Uint128::new(100), 10)
+            .unwrap();
+        snapshots
+            .save_balance(&mut storage, &alice, Uint128::new(150), 20)
+            .unwrap();
+        snapshots
+            .save_balance(&mut storage, &bob, Uint128::new(50), 20)
+            .unwrap();
+
+        // A balance saved at height h is only visible from h + 1.
+        let balance_at = |address, height| snapshots.balance_at(&storage, address, height).unwrap();
+        assert_eq!(balance_at(&alice, 10), Uint128::zero());
+        assert_eq!(balance_at(&alice, 11), Uint128::new(100));
+        assert_eq!(balance_at(&alice, 20), Uint128::new(100));
+        assert_eq!(balance_at(&alice, 21), Uint128::new(150));
+
+        // Bob was first written after these heights.
+        assert_eq!(balance_at(&bob, 11), Uint128::zero());
+        assert_eq!(balance_at(&bob, 20), Uint128::zero());
+        assert_eq!(balance_at(&bob, 21), Uint128::new(50));
+    }
+
+    #[test]
+    fn total_supply_at_returns_supply_at_start_of_block() {
+        let snapshots = ShareSnapshots::new();
+        let mut storage = MockStorage::new();
+
+        snapshots
+            .save_total_supply(&mut storage, Uint128::new(100), 10)
+            .unwrap();
+
+        assert_eq!(
+            snapshots.total_supply_at(&storage, 10).unwrap(),
+            Uint128::zero()
+        );
+        assert_eq!(
+            snapshots.total_supply_at(&storage, 11).unwrap(),
+            Uint128::new(100)
+        );
+    }
+}
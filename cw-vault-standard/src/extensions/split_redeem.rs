@@ -0,0 +1,188 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, Coin, CosmosMsg, Decimal, StdError, StdResult, Uint128, WasmMsg,
+};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// The maximum number of recipients of a single `RedeemSplit`.
+pub const MAX_SPLIT_RECIPIENTS: usize = 10;
+
+/// Additional ExecuteMsg variants for vaults that enable the SplitRedeem
+/// extension.
+#[cw_serde]
+pub enum SplitRedeemExecuteMsg {
+    /// Redeem vault tokens and send the withdrawn base tokens to multiple
+    /// recipients. The vault tokens must be passed in the funds parameter,
+    /// unless the vault is a cw4626 vault.
+    ///
+    /// Must fail if the recipients are invalid according to
+    /// [`validate_split_recipients`]. Any rounding remainder of the
+    /// withdrawn base tokens is sent to the last recipient.
+    RedeemSplit {
+        /// The amount of vault tokens to redeem.
+        amount: Uint128,
+        /// The recipients of the withdrawn base tokens, together with the
+        /// share of the base tokens each of them receives.
+        recipients: Vec<(String, Decimal)>,
+    },
+}
+
+impl SplitRedeemExecuteMsg {
+    /// Convert a [`SplitRedeemExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::SplitRedeem(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Validates the recipients of a `RedeemSplit` and returns them with checked
+/// addresses. The recipients are valid if:
+/// - there is at least one and at most [`MAX_SPLIT_RECIPIENTS`] recipients,
+/// - all addresses are valid and no address occurs more than once,
+/// - all shares are non-zero and they sum up to exactly one.
+pub fn validate_split_recipients(
+    api: &dyn Api,
+    recipients: &[(String, Decimal)],
+) -> StdResult<Vec<(Addr, Decimal)>> {
+    if recipients.is_empty() || recipients.len() > MAX_SPLIT_RECIPIENTS {
+        return Err(StdError::generic_err(format!(
+            "number of recipients must be between 1 and {MAX_SPLIT_RECIPIENTS}"
+        )));
+    }
+
+    let mut checked: Vec<(Addr, Decimal)> = Vec::with_capacity(recipients.len());
+    let mut total = Decimal::zero();
+    for (address, share) in recipients {
+        let addr = api.addr_validate(address)?;
+        if checked.iter().any(|(a, _)| a == addr) {
+            return Err(StdError::generic_err(format!(
+                "duplicate recipient: {addr}"
+            )));
+        }
+        if share.is_zero() {
+            return Err(StdError::generic_err(format!(
+                "zero share for recipient: {addr}"
+            )));
+        }
+        total = total.checked_add(*share)?;
+        checked.push((addr, *share));
+    }
+
+    if total != Decimal::one() {
+        return Err(StdError::generic_err(format!(
+            "recipient shares must sum up to 1, got {total}"
+        )));
+    }
+
+    Ok(checked)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::testing::MockApi;
+
+    use super::*;
+
+    fn recipients(shares: &[(&str, &str)]) -> Vec<(String, Decimal)> {
+        shares
+            .iter()
+            .map(|(address, share)| (address.to_string(), Decimal::from_str(share).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn accepts_valid_split_in_input_order() {
+        let api = MockApi::default();
+        let checked =
+            validate_split_recipients(&api, &recipients(&[("bob", "0.8"), ("alice", "0.2")]))
+                .unwrap();
+        assert_eq!(
+            checked,
+            vec![
+                (Addr::unchecked("bob"), Decimal::percent(80)),
+                (Addr::unchecked("alice"), Decimal::percent(20)),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_empty_recipients() {
+        let api = MockApi::default();
+        let err = validate_split_recipients(&api, &[]).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("number of recipients must be between 1 and 10")
+        );
+    }
+
+    #[test]
+    fn rejects_too_many_recipients() {
+        let api = MockApi::default();
+        let recipients: Vec<_> = (0..=MAX_SPLIT_RECIPIENTS)
+            .map(|i| (format!("recipient{i}"), Decimal::percent(1)))
+            .collect();
+        let err = validate_split_recipients(&api, &recipients).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("number of recipients must be between 1 and 10")
+        );
+    }
+
+    #[test]
+    fn rejects_duplicate_recipient() {
+        let api = MockApi::default();
+        let err = validate_split_recipients(
+            &api,
+            &recipients(&[("alice", "0.5"), ("bob", "0.25"), ("alice", "0.25")]),
+        )
+        .unwrap_err();
+        assert_eq!(err, StdError::generic_err("duplicate recipient: alice"));
+    }
+
+    #[test]
+    fn rejects_zero_share() {
+        let api = MockApi::default();
+        let err = validate_split_recipients(&api, &recipients(&[("alice", "1"), ("bob", "0")]))
+            .unwrap_err();
+        assert_eq!(err, StdError::generic_err("zero share for recipient: bob"));
+    }
+
+    #[test]
+    fn rejects_shares_not_summing_to_one() {
+        let api = MockApi::default();
+        let err = validate_split_recipients(&api, &recipients(&[("alice", "0.5"), ("bob", "0.4")]))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("recipient shares must sum up to 1, got 0.9")
+        );
+
+        let err = validate_split_recipients(&api, &recipients(&[("alice", "0.5"), ("bob", "0.6")]))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("recipient shares must sum up to 1, got 1.1")
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_address() {
+        let api = MockApi::default();
+        let err = validate_split_recipients(&api, &recipients(&[("alice", "0.5"), ("B", "0.5")]))
+            .unwrap_err();
+        assert_eq!(err, api.addr_validate("B").unwrap_err());
+    }
+}
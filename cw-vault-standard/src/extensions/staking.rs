@@ -0,0 +1,79 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Staking
+/// extension.
+#[cw_serde]
+pub enum StakingExecuteMsg {
+    /// Callable by vault admin to add a validator to the validator set of the
+    /// vault.
+    AddValidator {
+        /// The operator address of the validator.
+        validator: String,
+        /// The share of the vault's delegations to delegate to the validator.
+        weight: Decimal,
+    },
+
+    /// Callable by vault admin to remove a validator from the validator set
+    /// of the vault. The vault should redelegate or undelegate the tokens
+    /// delegated to the validator.
+    RemoveValidator {
+        /// The operator address of the validator.
+        validator: String,
+    },
+
+    /// Callable by vault admin to update the delegation weights of the
+    /// validators. The weights of all validators must sum up to one.
+    UpdateDelegationWeights {
+        /// The new weights, as a list of validator operator addresses and
+        /// weights. Validators that are not included are left unchanged.
+        weights: Vec<(String, Decimal)>,
+    },
+}
+
+impl StakingExecuteMsg {
+    /// Convert a [`StakingExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Staking(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Staking extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum StakingQueryMsg {
+    /// Returns a `Vec<ValidatorDelegation>` containing the validator set of
+    /// the vault and the current delegation to each validator.
+    #[returns(Vec<ValidatorDelegation>)]
+    Delegations {},
+
+    /// Returns `Uint128` total amount of tokens that are currently being
+    /// unbonded by the vault.
+    #[returns(Uint128)]
+    UnbondingTotal {},
+}
+
+/// Info about a validator in the validator set of a vault.
+#[cw_serde]
+pub struct ValidatorDelegation {
+    /// The operator address of the validator.
+    pub validator: String,
+    /// The target share of the vault's delegations to delegate to the
+    /// validator.
+    pub weight: Decimal,
+    /// The amount of tokens currently delegated to the validator.
+    pub delegated: Uint128,
+}
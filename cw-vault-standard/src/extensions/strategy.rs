@@ -0,0 +1,120 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `ReportStrategy`.
+pub const STRATEGY_REPORTED_EVENT_TYPE: &str = "strategy_reported";
+/// Key for the attribute in the "strategy reported" event containing the
+/// address of the strategy.
+pub const STRATEGY_REPORTED_STRATEGY_ATTR_KEY: &str = "strategy";
+/// Key for the attribute in the "strategy reported" event containing the
+/// reported gains.
+pub const STRATEGY_REPORTED_GAINS_ATTR_KEY: &str = "gains";
+/// Key for the attribute in the "strategy reported" event containing the
+/// reported losses.
+pub const STRATEGY_REPORTED_LOSSES_ATTR_KEY: &str = "losses";
+/// Key for the attribute in the "strategy reported" event containing the
+/// reported repaid debt.
+pub const STRATEGY_REPORTED_DEBT_REPAID_ATTR_KEY: &str = "debt_repaid";
+
+/// Additional ExecuteMsg variants for vaults that enable the Strategy
+/// extension.
+#[cw_serde]
+pub enum StrategyExecuteMsg {
+    /// Callable by vault admin to add a strategy that the vault can allocate
+    /// assets to.
+    AddStrategy {
+        /// The address of the strategy.
+        strategy: String,
+        /// The target share of the vault's total assets to allocate to the
+        /// strategy.
+        weight: Decimal,
+    },
+
+    /// Callable by vault admin to remove a strategy. The vault should
+    /// withdraw all assets deployed to the strategy.
+    RemoveStrategy {
+        /// The address of the strategy.
+        strategy: String,
+    },
+
+    /// Callable by vault admin to update the target weights of the
+    /// strategies. The weights of all strategies must sum up to at most one,
+    /// with the remainder being kept idle in the vault.
+    UpdateWeights {
+        /// The new weights, as a list of strategy addresses and weights.
+        /// Strategies that are not included are left unchanged.
+        weights: Vec<(String, Decimal)>,
+    },
+
+    /// Called by a strategy to report its profit and loss since its last
+    /// report. Must fail if the caller is not a registered strategy. Any base
+    /// tokens for gains or repaid debt must be passed in the funds parameter.
+    /// Emits an event with type `STRATEGY_REPORTED_EVENT_TYPE` with attributes
+    /// with keys `STRATEGY_REPORTED_STRATEGY_ATTR_KEY`,
+    /// `STRATEGY_REPORTED_GAINS_ATTR_KEY`, `STRATEGY_REPORTED_LOSSES_ATTR_KEY`
+    /// and `STRATEGY_REPORTED_DEBT_REPAID_ATTR_KEY`.
+    ReportStrategy {
+        /// The amount of base tokens the strategy has gained.
+        gains: Uint128,
+        /// The amount of base tokens the strategy has lost.
+        losses: Uint128,
+        /// The amount of base tokens the strategy returns to the vault.
+        debt_repaid: Uint128,
+    },
+}
+
+impl StrategyExecuteMsg {
+    /// Convert a [`StrategyExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Strategy(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Strategy
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum StrategyQueryMsg {
+    /// Returns a `Vec<StrategyInfo>` containing all strategies of the vault.
+    #[returns(Vec<StrategyInfo>)]
+    Strategies {
+        /// Return results only after this strategy address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns `Uint128` amount of assets, denominated in base tokens, that
+    /// are deployed to the strategy.
+    #[returns(Uint128)]
+    StrategyAssets {
+        /// The address of the strategy.
+        strategy: String,
+    },
+}
+
+/// Info about a strategy of a vault.
+#[cw_serde]
+pub struct StrategyInfo {
+    /// The address of the strategy.
+    pub address: Addr,
+    /// The target share of the vault's total assets to allocate to the
+    /// strategy.
+    pub weight: Decimal,
+    /// The amount of assets, denominated in base tokens, that are deployed to
+    /// the strategy.
+    pub assets_deployed: Uint128,
+}
@@ -0,0 +1,96 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Timestamp, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `AnnounceSunset`.
+pub const VAULT_SUNSET_ANNOUNCED_EVENT_TYPE: &str = "vault_sunset_announced";
+/// Type for the event emitted on call to `CancelSunset`.
+pub const VAULT_SUNSET_CANCELLED_EVENT_TYPE: &str = "vault_sunset_cancelled";
+/// Key for the attribute in the "vault sunset announced" event containing the
+/// time, in seconds, after which deposits are no longer accepted.
+pub const DEPOSITS_END_ATTR_KEY: &str = "deposits_end";
+/// Key for the attribute in the "vault sunset announced" event containing the
+/// time, in seconds, at which the vault reaches its end of life.
+pub const SUNSET_ATTR_KEY: &str = "sunset";
+
+/// Additional ExecuteMsg variants for vaults that enable the Sunset
+/// extension.
+///
+/// After `deposits_end` of an announced sunset has passed, `Deposit` must
+/// fail. `Redeem` must keep working until the sunset, after which redemptions
+/// follow the [`PostSunsetTerms`] of the schedule.
+#[cw_serde]
+pub enum SunsetExecuteMsg {
+    /// Callable by vault admin to announce the end of life of the vault.
+    /// Emits an event with type `VAULT_SUNSET_ANNOUNCED_EVENT_TYPE` with
+    /// attributes with keys `DEPOSITS_END_ATTR_KEY` and `SUNSET_ATTR_KEY`.
+    AnnounceSunset {
+        /// The wind-down schedule of the vault.
+        schedule: SunsetSchedule,
+    },
+
+    /// Callable by vault admin to cancel an announced sunset. Must fail if
+    /// the sunset has already passed.
+    /// Emits an event with type `VAULT_SUNSET_CANCELLED_EVENT_TYPE`.
+    CancelSunset {},
+}
+
+impl SunsetExecuteMsg {
+    /// Convert a [`SunsetExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Sunset(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Sunset extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum SunsetQueryMsg {
+    /// Returns an `Option<SunsetSchedule>` containing the wind-down schedule
+    /// of the vault, or `None` if no sunset has been announced.
+    #[returns(Option<SunsetSchedule>)]
+    SunsetSchedule {},
+}
+
+/// The wind-down schedule of a vault.
+#[cw_serde]
+pub struct SunsetSchedule {
+    /// The time after which deposits are no longer accepted.
+    pub deposits_end: Timestamp,
+    /// The time at which the vault reaches its end of life and has unwound
+    /// all positions into base tokens.
+    pub sunset: Timestamp,
+    /// The terms for redemptions after the sunset.
+    pub post_sunset_terms: PostSunsetTerms,
+}
+
+/// The terms for redemptions from a vault after its sunset.
+#[cw_serde]
+pub enum PostSunsetTerms {
+    /// Vault tokens can be redeemed from the vault indefinitely.
+    RedeemFromVault {},
+    /// Vault tokens can be redeemed from the vault until the given time,
+    /// after which the remaining base tokens are sent to the given
+    /// successor, e.g. a claims contract.
+    RedeemUntil {
+        /// The time until which vault tokens can be redeemed.
+        deadline: Timestamp,
+        /// The address that receives the unclaimed base tokens.
+        successor: String,
+    },
+    /// The base tokens of all remaining holders are sent to them
+    /// automatically at the sunset.
+    Distributed {},
+}
@@ -0,0 +1,99 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Binary, Coin, CosmosMsg, StdResult, WasmMsg};
+use cw_utils::{Duration, Expiration};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `ScheduleOperation`.
+pub const OPERATION_SCHEDULED_EVENT_TYPE: &str = "operation_scheduled";
+/// Type for the event emitted on call to `ExecuteOperation`.
+pub const OPERATION_EXECUTED_EVENT_TYPE: &str = "operation_executed";
+/// Type for the event emitted on call to `CancelOperation`.
+pub const OPERATION_CANCELLED_EVENT_TYPE: &str = "operation_cancelled";
+/// Key for the operation id attribute in the "operation scheduled",
+/// "operation executed" and "operation cancelled" events.
+pub const OPERATION_ID_ATTR_KEY: &str = "operation_id";
+
+/// Additional ExecuteMsg variants for vaults that enable the Timelock
+/// extension.
+#[cw_serde]
+pub enum TimelockExecuteMsg {
+    /// Callable by vault admin to schedule an admin operation, which can be
+    /// executed once the timelock delay has passed.
+    /// Emits an event with type `OPERATION_SCHEDULED_EVENT_TYPE` with an
+    /// attribute with key `OPERATION_ID_ATTR_KEY` containing an u64
+    /// operation_id.
+    ScheduleOperation {
+        /// The JSON encoded ExecuteMsg of the vault that should be executed,
+        /// e.g. an `UpdateConfig` message.
+        msg: Binary,
+    },
+
+    /// Execute a scheduled operation. Must fail if the timelock delay of the
+    /// operation has not yet passed.
+    /// Emits an event with type `OPERATION_EXECUTED_EVENT_TYPE` with an
+    /// attribute with key `OPERATION_ID_ATTR_KEY`.
+    ExecuteOperation {
+        /// The ID of the operation to execute.
+        operation_id: u64,
+    },
+
+    /// Callable by vault admin to cancel a scheduled operation.
+    /// Emits an event with type `OPERATION_CANCELLED_EVENT_TYPE` with an
+    /// attribute with key `OPERATION_ID_ATTR_KEY`.
+    CancelOperation {
+        /// The ID of the operation to cancel.
+        operation_id: u64,
+    },
+}
+
+impl TimelockExecuteMsg {
+    /// Convert a [`TimelockExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Timelock(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Timelock
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum TimelockQueryMsg {
+    /// Returns a `Vec<ScheduledOperation>` containing all operations that
+    /// have been scheduled but not yet executed or cancelled.
+    #[returns(Vec<ScheduledOperation>)]
+    ScheduledOperations {
+        /// Return results only after this operation_id
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns `cw_utils::Duration` delay between scheduling and executing
+    /// an operation.
+    #[returns(Duration)]
+    TimelockDelay {},
+}
+
+/// Info about a scheduled admin operation.
+#[cw_serde]
+pub struct ScheduledOperation {
+    /// The ID of the operation.
+    pub id: u64,
+    /// The JSON encoded ExecuteMsg of the vault that will be executed.
+    pub msg: Binary,
+    /// A `cw_utils::Expiration` containing information about when the
+    /// operation can be executed.
+    pub executable_at: Expiration,
+}
@@ -0,0 +1,236 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Decimal256, Order, StdError, StdResult, Storage, Timestamp};
+use cw_storage_plus::{Bound, Map};
+
+/// Additional QueryMsg variants for vaults that enable the Twap extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum TwapQueryMsg {
+    /// Returns `Decimal` time-weighted average price of one vault token,
+    /// denominated in base tokens, over the last `window_secs` seconds.
+    ///
+    /// Must fail if the vault does not have price history covering the whole
+    /// window.
+    #[returns(Decimal)]
+    Twap {
+        /// The length of the averaging window in seconds.
+        window_secs: u64,
+    },
+}
+
+/// A price-per-share checkpoint stored by [`TwapCheckpoints`].
+#[cw_serde]
+pub struct Checkpoint {
+    /// The price of one vault token, denominated in base tokens, from the time
+    /// of this checkpoint until the next one.
+    pub price_per_share: Decimal,
+    /// The sum of the price per share multiplied by the number of seconds it
+    /// was valid, from the first checkpoint up to the time of this one.
+    pub cumulative_price: Decimal256,
+}
+
+/// Recommended storage helper for vaults implementing the Twap extension.
+///
+/// The vault should call [`TwapCheckpoints::record`] with the new price per
+/// share whenever it changes, e.g. after every deposit, redeem and
+/// compounding, and can then answer [`TwapQueryMsg::Twap`] with
+/// [`TwapCheckpoints::twap`].
+pub struct TwapCheckpoints<'a> {
+    checkpoints: Map<'a, u64, Checkpoint>,
+}
+
+impl<'a> TwapCheckpoints<'a> {
+    /// Create a new TwapCheckpoints instance storing its checkpoints under
+    /// `namespace`.
+    pub const fn new(namespace: &'a str) -> Self {
+        Self {
+            checkpoints: Map::new(namespace),
+        }
+    }
+
+    /// Record the price per share at the given time. Must be called with
+    /// non-decreasing times.
+    pub fn record(
+        &self,
+        storage: &mut dyn Storage,
+        time: Timestamp,
+        price_per_share: Decimal,
+    ) -> StdResult<()> {
+        let now = time.seconds();
+        let latest = self
+            .checkpoints
+            .range(storage, None, None, Order::Descending)
+            .next()
+            .transpose()?;
+        let cumulative_price = match latest {
+            Some((ts, _)) if ts > now => {
+                return Err(StdError::generic_err(
+                    "can not record a checkpoint before the latest checkpoint",
+                ))
+            }
+            Some((ts, last)) => Self::cumulative_at(ts, &last, now)?,
+            None => Decimal256::zero(),
+        };
+        self.checkpoints.save(
+            storage,
+            now,
+            &Checkpoint {
+                price_per_share,
+                cumulative_price,
+            },
+        )
+    }
+
+    /// Returns the time-weighted average price per share over the
+    /// `window_secs` seconds before `time`.
+    pub fn twap(
+        &self,
+        storage: &dyn Storage,
+        time: Timestamp,
+        window_secs: u64,
+    ) -> StdResult<Decimal> {
+        let end = time.seconds();
+        let start = end
+            .checked_sub(window_secs)
+            .ok_or_else(|| StdError::generic_err("twap window is longer than the chain history"))?;
+
+        let (start_ts, start_cp) = self
+            .last_at_or_before(storage, start)?
+            .ok_or_else(|| StdError::generic_err("not enough price history for twap window"))?;
+        if window_secs == 0 {
+            return Ok(start_cp.price_per_share);
+        }
+        let (end_ts, end_cp) = self
+            .last_at_or_before(storage, end)?
+            .ok_or_else(|| StdError::generic_err("not enough price history for twap window"))?;
+
+        let cumulative_start = Self::cumulative_at(start_ts, &start_cp, start)?;
+        let cumulative_end = Self::cumulative_at(end_ts, &end_cp, end)?;
+        let twap = cumulative_end
+            .checked_sub(cumulative_start)?
+            .checked_div(Decimal256::from_ratio(window_secs, 1u64))
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+        Decimal::try_from(twap).map_err(|e| StdError::generic_err(e.to_string()))
+    }
+
+    /// Returns the latest checkpoint at or before `time` (in seconds).
+    fn last_at_or_before(
+        &self,
+        storage: &dyn Storage,
+        time: u64,
+    ) -> StdResult<Option<(u64, Checkpoint)>> {
+        self.checkpoints
+            .range(
+                storage,
+                None,
+                Some(Bound::inclusive(time)),
+                Order::Descending,
+            )
+            .next()
+            .transpose()
+    }
+
+    /// Returns the cumulative price at `time`, extrapolated from the
+    /// checkpoint recorded at `checkpoint_time`.
+    fn cumulative_at(
+        checkpoint_time: u64,
+        checkpoint: &Checkpoint,
+        time: u64,
+    ) -> StdResult<Decimal256> {
+        let elapsed = Decimal256::from_ratio(time - checkpoint_time, 1u64);
+        Ok(checkpoint
+            .cumulative_price
+            .checked_add(Decimal256::from(checkpoint.price_per_share).checked_mul(elapsed)?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    const CHECKPOINTS: TwapCheckpoints = TwapCheckpoints::new("twap");
+
+    fn record(storage: &mut dyn Storage, seconds: u64, price: &str) {
+        CHECKPOINTS
+            .record(
+                storage,
+                Timestamp::from_seconds(seconds),
+                price.parse().unwrap(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn fails_without_checkpoint_before_window_start() {
+        let mut storage = MockStorage::new();
+        record(&mut storage, 100, "1.5");
+
+        let err = CHECKPOINTS
+            .twap(&storage, Timestamp::from_seconds(150), 100)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("not enough price history for twap window")
+        );
+    }
+
+    #[test]
+    fn zero_window_returns_current_price() {
+        let mut storage = MockStorage::new();
+        record(&mut storage, 100, "1");
+        record(&mut storage, 200, "2");
+
+        let twap = CHECKPOINTS
+            .twap(&storage, Timestamp::from_seconds(250), 0)
+            .unwrap();
+        assert_eq!(twap, Decimal::percent(200));
+    }
+
+    #[test]
+    fn single_checkpoint_returns_its_price() {
+        let mut storage = MockStorage::new();
+        record(&mut storage, 100, "1.5");
+
+        let twap = CHECKPOINTS
+            .twap(&storage, Timestamp::from_seconds(200), 100)
+            .unwrap();
+        assert_eq!(twap, Decimal::percent(150));
+    }
+
+    #[test]
+    fn weights_prices_by_time_across_checkpoints() {
+        let mut storage = MockStorage::new();
+        record(&mut storage, 100, "1");
+        record(&mut storage, 200, "2");
+        record(&mut storage, 250, "4");
+
+        // 1 * 50 + 2 * 50 + 4 * 50 = 350 over 150 seconds.
+        let twap = CHECKPOINTS
+            .twap(&storage, Timestamp::from_seconds(300), 150)
+            .unwrap();
+        assert_eq!(twap, Decimal::from_ratio(7u128, 3u128));
+
+        // 1 * 100 + 2 * 50 + 4 * 50 = 400 over 200 seconds.
+        let twap = CHECKPOINTS
+            .twap(&storage, Timestamp::from_seconds(300), 200)
+            .unwrap();
+        assert_eq!(twap, Decimal::percent(200));
+    }
+
+    #[test]
+    fn rejects_checkpoint_before_latest() {
+        let mut storage = MockStorage::new();
+        record(&mut storage, 200, "1");
+
+        let err = CHECKPOINTS
+            .record(&mut storage, Timestamp::from_seconds(100), Decimal::one())
+            .unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("can not record a checkpoint before the latest checkpoint")
+        );
+    }
+}
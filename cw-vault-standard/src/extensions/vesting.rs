@@ -0,0 +1,71 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Vesting
+/// extension.
+#[cw_serde]
+pub enum VestingExecuteMsg {
+    /// Claim all vault tokens of the caller that have vested but not yet
+    /// been claimed.
+    ClaimVested {
+        /// An optional field containing which address should receive the
+        /// vault tokens. If not set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+}
+
+impl VestingExecuteMsg {
+    /// Convert a [`VestingExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Vesting(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Vesting extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum VestingQueryMsg {
+    /// Returns a `Vec<VestingPosition>` containing all vesting positions of
+    /// the `owner` that have not been fully claimed.
+    #[returns(Vec<VestingPosition>)]
+    VestingPositions {
+        /// The address of the owner of the vesting positions
+        owner: String,
+        /// Return results only after this position id
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// Info about vault tokens that vest linearly over time.
+#[cw_serde]
+pub struct VestingPosition {
+    /// The ID of the vesting position.
+    pub id: u64,
+    /// The address of the owner of the vesting position.
+    pub owner: Addr,
+    /// The total amount of vault tokens in the vesting position.
+    pub total_shares: Uint128,
+    /// The amount of vault tokens that have vested so far.
+    pub vested_shares: Uint128,
+    /// The amount of vault tokens that have already been claimed.
+    pub claimed_shares: Uint128,
+    /// The time at which vesting starts.
+    pub start: Timestamp,
+    /// The time at which the position is fully vested.
+    pub end: Timestamp,
+}
@@ -0,0 +1,95 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `WrapPosition`.
+pub const POSITION_WRAPPED_EVENT_TYPE: &str = "position_wrapped";
+/// Type for the event emitted on call to `UnwrapPosition`.
+pub const POSITION_UNWRAPPED_EVENT_TYPE: &str = "position_unwrapped";
+/// Key for the attribute in the "position wrapped" and "position unwrapped"
+/// events containing the token id of the position NFT.
+pub const POSITION_TOKEN_ID_ATTR_KEY: &str = "token_id";
+/// Key for the attribute in the "position wrapped" and "position unwrapped"
+/// events containing the amount of vault tokens in the position.
+pub const POSITION_SHARES_ATTR_KEY: &str = "shares";
+
+/// Additional ExecuteMsg variants for vaults that enable the WrappedPosition
+/// extension.
+#[cw_serde]
+pub enum WrappedPositionExecuteMsg {
+    /// Wrap vault tokens into a position NFT. The vault tokens must be passed
+    /// in the funds parameter, unless the vault is a cw4626 vault. The vault
+    /// holds the vault tokens in escrow and mints a cw721 NFT on the position
+    /// NFT contract representing them.
+    /// Emits an event with type `POSITION_WRAPPED_EVENT_TYPE` with attributes
+    /// with keys `POSITION_TOKEN_ID_ATTR_KEY` and `POSITION_SHARES_ATTR_KEY`.
+    WrapPosition {
+        /// The amount of vault tokens to wrap.
+        amount: Uint128,
+        /// An optional field containing the owner of the minted NFT. If not
+        /// set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+
+    /// Unwrap a position NFT, burning the NFT and releasing the vault tokens
+    /// it represents. Callable only by the owner of the NFT.
+    /// Emits an event with type `POSITION_UNWRAPPED_EVENT_TYPE` with
+    /// attributes with keys `POSITION_TOKEN_ID_ATTR_KEY` and
+    /// `POSITION_SHARES_ATTR_KEY`.
+    UnwrapPosition {
+        /// The token id of the position NFT to unwrap.
+        token_id: String,
+        /// An optional field containing which address should receive the
+        /// vault tokens. If not set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+}
+
+impl WrappedPositionExecuteMsg {
+    /// Convert a [`WrappedPositionExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::WrappedPosition(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the WrappedPosition
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum WrappedPositionQueryMsg {
+    /// Returns the address of the cw721 contract that the position NFTs are
+    /// minted on.
+    #[returns(Addr)]
+    PositionNftContract {},
+
+    /// Returns [`WrappedPosition`] resolving a position NFT to the vault
+    /// tokens it represents.
+    #[returns(WrappedPosition)]
+    WrappedPosition {
+        /// The token id of the position NFT.
+        token_id: String,
+    },
+}
+
+/// The vault tokens represented by a position NFT.
+#[cw_serde]
+pub struct WrappedPosition {
+    /// The token id of the position NFT.
+    pub token_id: String,
+    /// The amount of vault tokens held in escrow for the position.
+    pub shares: Uint128,
+    /// The amount of base tokens the vault tokens are currently worth.
+    pub base_tokens: Uint128,
+}
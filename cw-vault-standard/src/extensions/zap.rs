@@ -0,0 +1,85 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Zap extension.
+#[cw_serde]
+pub enum ZapExecuteMsg {
+    /// Called to swap a single asset into the base token and deposit it into
+    /// the vault in one step. The asset must be passed in the funds parameter.
+    ZapIn {
+        /// The asset to zap into the vault.
+        asset: Coin,
+        /// The minimum amount of vault tokens to receive. The zap must fail if
+        /// fewer vault tokens would be minted.
+        min_shares_out: Uint128,
+        /// The optional recipient of the vault token. If not set, the caller
+        /// address will be used instead.
+        recipient: Option<String>,
+    },
+
+    /// Called to redeem vault tokens and swap the withdrawn base tokens into a
+    /// single asset in one step. The native vault token must be passed in the
+    /// funds parameter.
+    ZapOut {
+        /// The amount of vault tokens to redeem.
+        shares: Uint128,
+        /// The denom of the asset to receive.
+        receive_asset: String,
+        /// The minimum amount of `receive_asset` to receive. The zap must fail
+        /// if less would be received.
+        min_out: Uint128,
+        /// An optional field containing which address should receive the
+        /// asset. If not set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+}
+
+impl ZapExecuteMsg {
+    /// Convert a [`ZapExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Zap(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Zap extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ZapQueryMsg {
+    /// Returns `Uint128` amount of vault tokens that will be returned for
+    /// zapping in the passed in `asset`.
+    ///
+    /// Must return as close to and no more than the exact amount of vault
+    /// tokens that would be minted in a `ZapIn` call in the same transaction.
+    #[returns(Uint128)]
+    PreviewZapIn {
+        /// The asset to preview zapping in.
+        asset: Coin,
+    },
+
+    /// Returns `Uint128` amount of `receive_asset` that would be returned for
+    /// zapping out `shares` amount of vault tokens.
+    ///
+    /// Must return as close to and no more than the exact amount of
+    /// `receive_asset` that would be returned in a `ZapOut` call in the same
+    /// transaction.
+    #[returns(Uint128)]
+    PreviewZapOut {
+        /// The amount of vault tokens to preview zapping out.
+        shares: Uint128,
+        /// The denom of the asset to receive.
+        receive_asset: String,
+    },
+}
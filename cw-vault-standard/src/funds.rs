@@ -0,0 +1,65 @@
+use cosmwasm_std::{Coin, MessageInfo, QuerierWrapper, Uint128};
+
+use crate::error::VaultStandardError;
+use crate::{QueryExtension, VaultContract};
+
+/// Asserts that the funds sent with a `Deposit` message consist of exactly
+/// `amount` of the base token `base_token` and nothing else.
+pub fn assert_deposit_funds(
+    info: &MessageInfo,
+    base_token: &str,
+    amount: Uint128,
+) -> Result<(), VaultStandardError> {
+    assert_exact_funds(info, base_token, amount)
+}
+
+/// Asserts that the funds sent with a `Redeem` or `Unlock` message consist of
+/// exactly `amount` of the vault token `vault_token` and nothing else.
+pub fn assert_vault_token_funds(
+    info: &MessageInfo,
+    vault_token: &str,
+    amount: Uint128,
+) -> Result<(), VaultStandardError> {
+    assert_exact_funds(info, vault_token, amount)
+}
+
+impl<E, Q> VaultContract<E, Q> {
+    /// Queries the vault token denom from the `Info` query of the vault and
+    /// asserts that the funds sent with a `Redeem` message consist of a single
+    /// non-zero coin of the vault token. Returns the amount of vault tokens
+    /// sent.
+    ///
+    /// Useful for contracts forwarding redemptions of their users to the
+    /// vault, as well as for vault implementations.
+    pub fn validate_redeem_funds(
+        &self,
+        querier: &QuerierWrapper,
+        info: &MessageInfo,
+    ) -> Result<Uint128, VaultStandardError>
+    where
+        Q: QueryExtension,
+    {
+        let vault_token = self.query_vault_info(querier)?.vault_token;
+        match info.funds.as_slice() {
+            [coin] if coin.denom == vault_token && !coin.amount.is_zero() => Ok(coin.amount),
+            received => Err(VaultStandardError::InvalidVaultTokenFunds {
+                vault_token,
+                received: received.to_vec(),
+            }),
+        }
+    }
+}
+
+fn assert_exact_funds(
+    info: &MessageInfo,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), VaultStandardError> {
+    match info.funds.as_slice() {
+        [coin] if coin.denom == denom && coin.amount == amount => Ok(()),
+        received => Err(VaultStandardError::InvalidFunds {
+            expected: Coin::new(amount.u128(), denom),
+            received: received.to_vec(),
+        }),
+    }
+}
@@ -1,33 +1,107 @@
+use std::cell::{Cell, OnceCell};
+use std::fmt;
 use std::marker::PhantomData;
 
 use cosmwasm_schema::cw_serde;
+#[cfg(feature = "cw4626")]
+use cosmwasm_std::Binary;
+#[cfg(any(feature = "cw4626", feature = "multi-asset"))]
+use cosmwasm_std::Coin;
 use cosmwasm_std::{
-    coin, to_binary, Addr, Api, CosmosMsg, QuerierWrapper, StdResult, Uint128, WasmMsg,
+    coin, to_json_binary, Addr, Api, CosmosMsg, Decimal, QuerierWrapper, StdError, StdResult,
+    SubMsg, Uint128, Uint256, WasmMsg,
 };
-use schemars::JsonSchema;
+#[cfg(feature = "cw4626")]
+use cw20::{AllowanceResponse, BalanceResponse, Expiration};
+#[cfg(feature = "cw20")]
+use cw20::{Cw20ExecuteMsg, Cw20QueryMsg, TokenInfoResponse};
+#[cfg(feature = "cw-asset")]
+use cw_asset::{Asset, AssetInfo};
+#[cfg(feature = "lockup")]
+use cw_utils::Duration;
+#[cfg(feature = "cw4626")]
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+use crate::error::VaultStandardError;
+#[cfg(feature = "batch-preview")]
+use crate::extensions::batch_preview::{BatchPreviewQueryMsg, BATCH_PREVIEW_EXTENSION_NAME};
+#[cfg(feature = "cw4626")]
+use crate::extensions::cw4626::{Cw4626ExecuteMsg, Cw4626QueryMsg};
+#[cfg(feature = "force-unlock")]
+use crate::extensions::force_unlock::{
+    ForceUnlockExecuteMsg, ForceUnlockQueryMsg, FORCE_UNLOCK_EXTENSION_NAME,
+};
+#[cfg(feature = "lockup")]
+use crate::extensions::lockup::{
+    LockupExecuteMsg, LockupQueryMsg, UnlockingPosition, LOCKUP_EXTENSION_NAME,
+};
+#[cfg(feature = "multi-asset")]
+use crate::extensions::multi_asset::{MultiAssetExecuteMsg, MultiAssetQueryMsg};
+use crate::math::{self, Rounding};
+#[cfg(feature = "cw4626")]
+use crate::token::VaultToken;
 use crate::{
     ExtensionExecuteMsg, ExtensionQueryMsg, VaultInfoResponse, VaultStandardExecuteMsg,
     VaultStandardInfoResponse, VaultStandardQueryMsg,
 };
 
+/// Marker trait for the extension enum of the vault standard ExecuteMsg used
+/// by the helpers, implemented for all serializable types.
+pub trait ExecuteExtension: Serialize {}
+
+impl<T: Serialize> ExecuteExtension for T {}
+
+/// Marker trait for the extension enum of the vault standard QueryMsg used by
+/// the helpers, implemented for all serializable types.
+pub trait QueryExtension: Serialize {}
+
+impl<T: Serialize> QueryExtension for T {}
+
+/// Rejects zero amounts and the vault itself as recipient in messages to the
+/// vault `vault`, which the vault would otherwise reject with an opaque error
+/// or, for the recipient, lock the tokens in the vault.
+pub(crate) fn validate_msg(
+    vault: &Addr,
+    amount: Uint128,
+    recipient: &Option<String>,
+) -> Result<(), VaultStandardError> {
+    if amount.is_zero() {
+        return Err(VaultStandardError::ZeroAmount {});
+    }
+    validate_recipient(vault, recipient)
+}
+
+/// Rejects the vault `vault` itself as recipient in messages to the vault.
+pub(crate) fn validate_recipient(
+    vault: &Addr,
+    recipient: &Option<String>,
+) -> Result<(), VaultStandardError> {
+    if recipient.as_deref() == Some(vault.as_str()) {
+        return Err(VaultStandardError::RecipientIsVault {
+            vault: vault.clone(),
+        });
+    }
+    Ok(())
+}
+
 /// A helper struct to interact with a vault contract that adheres to the vault
 /// standard. This struct contains an unchecked address. By calling the `check`
 /// method, the address is checked against the api and the checked version of
 /// the struct is returned.
 #[cw_serde]
+#[schemars(rename = "VaultContractUnchecked", bound = "")]
 pub struct VaultContractUnchecked<E = ExtensionExecuteMsg, Q = ExtensionQueryMsg> {
     pub addr: String,
+    #[serde(default, skip_serializing)]
+    #[schemars(skip)]
     execute_msg_extension: PhantomData<E>,
+    #[serde(default, skip_serializing)]
+    #[schemars(skip)]
     query_msg_extension: PhantomData<Q>,
 }
 
-impl<E, Q> VaultContractUnchecked<E, Q>
-where
-    E: Serialize,
-    Q: Serialize + JsonSchema,
-{
+impl<E, Q> VaultContractUnchecked<E, Q> {
     /// Create a new VaultContractUnchecked instance.
     pub fn new(addr: &str) -> Self {
         Self {
@@ -38,29 +112,73 @@ where
     }
 
     /// Check the address against the api and return a checked version of the
-    /// struct.
-    pub fn check(&self, api: &dyn Api) -> StdResult<VaultContract<E, Q>> {
-        Ok(VaultContract::new(&api.addr_validate(&self.addr)?))
+    /// struct, consuming self.
+    pub fn check(self, api: &dyn Api) -> StdResult<VaultContract<E, Q>> {
+        Ok(VaultContract::from(api.addr_validate(&self.addr)?))
+    }
+
+    /// Alias of [`VaultContractUnchecked::check`].
+    pub fn into_checked(self, api: &dyn Api) -> StdResult<VaultContract<E, Q>> {
+        self.check(api)
+    }
+}
+
+impl<E, Q> From<String> for VaultContractUnchecked<E, Q> {
+    fn from(addr: String) -> Self {
+        Self {
+            addr,
+            execute_msg_extension: PhantomData,
+            query_msg_extension: PhantomData,
+        }
+    }
+}
+
+impl<E, Q> From<&str> for VaultContractUnchecked<E, Q> {
+    fn from(addr: &str) -> Self {
+        Self::from(addr.to_string())
+    }
+}
+
+impl<E, Q> From<VaultContract<E, Q>> for VaultContractUnchecked<E, Q> {
+    fn from(vault: VaultContract<E, Q>) -> Self {
+        Self::from(vault.addr.to_string())
+    }
+}
+
+impl<E, Q> AsRef<str> for VaultContractUnchecked<E, Q> {
+    fn as_ref(&self) -> &str {
+        &self.addr
+    }
+}
+
+impl<E, Q> fmt::Display for VaultContractUnchecked<E, Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.addr)
     }
 }
 
 /// A helper struct to interact with a vault contract that adheres to the vault
 /// standard.
+///
+/// The message constructors are generic over the custom message type `C` of
+/// the returned `CosmosMsg<C>`, so that they can be used directly on chains
+/// with custom messages.
 #[cw_serde]
+#[schemars(rename = "VaultContract", bound = "")]
 pub struct VaultContract<E = ExtensionExecuteMsg, Q = ExtensionQueryMsg> {
     /// The address of the vault contract.
     pub addr: Addr,
     /// The extension enum for ExecuteMsg variants.
+    #[serde(default, skip_serializing)]
+    #[schemars(skip)]
     execute_msg_extension: PhantomData<E>,
     /// The extension enum for QueryMsg variants.
+    #[serde(default, skip_serializing)]
+    #[schemars(skip)]
     query_msg_extension: PhantomData<Q>,
 }
 
-impl<E, Q> VaultContract<E, Q>
-where
-    E: Serialize,
-    Q: Serialize + JsonSchema,
-{
+impl<E, Q> VaultContract<E, Q> {
     /// Create a new VaultContract instance.
     pub fn new(addr: &Addr) -> Self {
         Self {
@@ -70,18 +188,23 @@ where
         }
     }
 
-    /// Returns a CosmosMsg to deposit base tokens into the vault.
-    pub fn deposit(
+    /// Returns a CosmosMsg to deposit base tokens into the vault. Returns an
+    /// error if `amount` is zero or `recipient` is the vault itself.
+    pub fn deposit<C>(
         &self,
         amount: impl Into<Uint128>,
         base_denom: &str,
         recipient: Option<String>,
-    ) -> StdResult<CosmosMsg> {
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
         let amount = amount.into();
+        validate_msg(&self.addr, amount, &recipient)?;
 
         Ok(WasmMsg::Execute {
             contract_addr: self.addr.to_string(),
-            msg: to_binary(&VaultStandardExecuteMsg::<E>::Deposit { amount, recipient })?,
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Deposit { amount, recipient })?,
             funds: vec![coin(amount.u128(), base_denom)],
         }
         .into())
@@ -89,54 +212,234 @@ where
 
     /// Returns a CosmosMsg to deposit tokens into the vault, leaving the native
     /// funds field empty. This is useful for depositing cw20 tokens. The
-    /// caller should have approved spend for the cw20 tokens first.
-    pub fn deposit_cw20(&self, amount: Uint128, recipient: Option<String>) -> StdResult<CosmosMsg> {
+    /// caller should have approved spend for the cw20 tokens first. Returns
+    /// an error if `amount` is zero or `recipient` is the vault itself.
+    pub fn deposit_cw20<C>(
+        &self,
+        amount: Uint128,
+        recipient: Option<String>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        validate_msg(&self.addr, amount, &recipient)?;
         Ok(WasmMsg::Execute {
             contract_addr: self.addr.to_string(),
-            msg: to_binary(&VaultStandardExecuteMsg::<E>::Deposit { amount, recipient })?,
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Deposit { amount, recipient })?,
             funds: vec![],
         }
         .into())
     }
 
-    /// Returns a CosmosMsg to redeem vault tokens from the vault.
-    pub fn redeem(
+    /// Returns a CosmosMsg to redeem vault tokens from the vault. Returns an
+    /// error if `amount` is zero or `recipient` is the vault itself.
+    pub fn redeem<C>(
         &self,
         amount: impl Into<Uint128>,
         vault_token_denom: &str,
         recipient: Option<String>,
-    ) -> StdResult<CosmosMsg> {
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
         let amount = amount.into();
+        validate_msg(&self.addr, amount, &recipient)?;
         Ok(WasmMsg::Execute {
             contract_addr: self.addr.to_string(),
-            msg: to_binary(&VaultStandardExecuteMsg::<E>::Redeem { amount, recipient })?,
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Redeem { amount, recipient })?,
             funds: vec![coin(amount.u128(), vault_token_denom)],
         }
         .into())
     }
 
+    /// Returns a CosmosMsg to deposit base tokens into the vault on behalf of
+    /// `recipient`, who receives the minted vault tokens. The recipient
+    /// address is validated against the api before building the message.
+    pub fn deposit_for<C>(
+        &self,
+        api: &dyn Api,
+        amount: impl Into<Uint128>,
+        base_denom: &str,
+        recipient: &str,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        let recipient = api.addr_validate(recipient)?;
+        self.deposit(amount, base_denom, Some(recipient.to_string()))
+    }
+
+    /// Returns a CosmosMsg to redeem vault tokens from the vault, sending the
+    /// base tokens to `recipient`. The recipient address is validated against
+    /// the api before building the message.
+    pub fn redeem_to<C>(
+        &self,
+        api: &dyn Api,
+        amount: impl Into<Uint128>,
+        vault_token_denom: &str,
+        recipient: &str,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        let recipient = api.addr_validate(recipient)?;
+        self.redeem(amount, vault_token_denom, Some(recipient.to_string()))
+    }
+
+    /// Returns a SubMsg to deposit base tokens into the vault, with a reply on
+    /// success with the given `reply_id`.
+    pub fn deposit_submsg<C>(
+        &self,
+        amount: impl Into<Uint128>,
+        base_denom: &str,
+        recipient: Option<String>,
+        reply_id: u64,
+    ) -> Result<SubMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        Ok(SubMsg::reply_on_success(
+            self.deposit(amount, base_denom, recipient)?,
+            reply_id,
+        ))
+    }
+
+    /// Returns a SubMsg to redeem vault tokens from the vault, with a reply on
+    /// success with the given `reply_id`.
+    pub fn redeem_submsg<C>(
+        &self,
+        amount: impl Into<Uint128>,
+        vault_token_denom: &str,
+        recipient: Option<String>,
+        reply_id: u64,
+    ) -> Result<SubMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        Ok(SubMsg::reply_on_success(
+            self.redeem(amount, vault_token_denom, recipient)?,
+            reply_id,
+        ))
+    }
+
+    /// Queries the vault token balance of `owner` and returns a CosmosMsg to
+    /// redeem all of it, or `None` if the balance is zero. The message must be
+    /// sent by `owner`. Returns an error if `recipient` is the vault itself.
+    ///
+    /// With the `cw4626` feature enabled, the balance of cw20 vault tokens is
+    /// queried from the vault. Otherwise the vault token is assumed to be a
+    /// native token.
+    pub fn redeem_all<C>(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+        recipient: Option<String>,
+    ) -> Result<Option<CosmosMsg<C>>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+        Q: QueryExtension,
+    {
+        #[cfg(feature = "cw4626")]
+        let (amount, funds) = {
+            let vault_token = self.query_vault_token(querier)?;
+            let amount = vault_token.balance(querier, owner)?;
+            (amount, vault_token.attach_to_redeem(amount))
+        };
+        #[cfg(not(feature = "cw4626"))]
+        let (amount, funds) = {
+            let denom = self.query_vault_info(querier)?.vault_token;
+            let amount = querier.query_balance(owner, &denom)?.amount;
+            (amount, vec![coin(amount.u128(), denom)])
+        };
+
+        if amount.is_zero() {
+            return Ok(None);
+        }
+        validate_msg(&self.addr, amount, &recipient)?;
+        Ok(Some(
+            WasmMsg::Execute {
+                contract_addr: self.addr.to_string(),
+                msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Redeem { amount, recipient })?,
+                funds,
+            }
+            .into(),
+        ))
+    }
+
     /// Queries the vault for the vault standard info
     pub fn query_vault_standard_info(
         &self,
         querier: &QuerierWrapper,
-    ) -> StdResult<VaultStandardInfoResponse> {
+    ) -> StdResult<VaultStandardInfoResponse>
+    where
+        Q: QueryExtension,
+    {
         querier.query_wasm_smart(
             &self.addr,
             &VaultStandardQueryMsg::<Q>::VaultStandardInfo {},
         )
     }
 
+    /// Queries the vault for the vault standard info and checks that the vault
+    /// implements at least version `min_version` of the vault standard and
+    /// all of the `required_extensions`, e.g. `["lockup", "force-unlock"]`.
+    /// Returns the vault standard info if the vault is compatible.
+    pub fn assert_compatible(
+        &self,
+        querier: &QuerierWrapper,
+        min_version: u16,
+        required_extensions: &[&str],
+    ) -> Result<VaultStandardInfoResponse, VaultStandardError>
+    where
+        Q: QueryExtension,
+    {
+        let info = self.query_vault_standard_info(querier)?;
+        if info.version < min_version {
+            return Err(VaultStandardError::IncompatibleVersion {
+                version: info.version,
+                min_version,
+            });
+        }
+        let missing: Vec<String> = required_extensions
+            .iter()
+            .filter(|ext| !info.extensions.iter().any(|e| e == *ext))
+            .map(ToString::to_string)
+            .collect();
+        if !missing.is_empty() {
+            return Err(VaultStandardError::MissingExtensions { missing });
+        }
+        Ok(info)
+    }
+
     /// Queries the vault for the vault info
-    pub fn query_vault_info(&self, querier: &QuerierWrapper) -> StdResult<VaultInfoResponse> {
+    pub fn query_vault_info(&self, querier: &QuerierWrapper) -> StdResult<VaultInfoResponse>
+    where
+        Q: QueryExtension,
+    {
         querier.query_wasm_smart(&self.addr, &VaultStandardQueryMsg::<Q>::Info {})
     }
 
+    /// Queries the vault for its vault token, resolving whether it is a
+    /// native or a cw20 token.
+    #[cfg(feature = "cw4626")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cw4626")))]
+    pub fn query_vault_token(&self, querier: &QuerierWrapper) -> StdResult<VaultToken>
+    where
+        Q: QueryExtension,
+    {
+        let info = self.query_vault_info(querier)?;
+        Ok(VaultToken::from_vault_info(&self.addr, &info))
+    }
+
     /// Queries the vault for a preview of a deposit
     pub fn query_preview_deposit(
         &self,
         querier: &QuerierWrapper,
         amount: impl Into<Uint128>,
-    ) -> StdResult<Uint128> {
+    ) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
         querier.query_wasm_smart(
             &self.addr,
             &VaultStandardQueryMsg::<Q>::PreviewDeposit {
@@ -150,7 +453,10 @@ where
         &self,
         querier: &QuerierWrapper,
         amount: impl Into<Uint128>,
-    ) -> StdResult<Uint128> {
+    ) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
         querier.query_wasm_smart(
             &self.addr,
             &VaultStandardQueryMsg::<Q>::PreviewRedeem {
@@ -159,13 +465,117 @@ where
         )
     }
 
+    /// Queries the vault for a preview of a deposit of each of `amounts`. The
+    /// returned amounts of vault tokens are in the same order as `amounts`.
+    ///
+    /// With the `batch-preview` feature enabled, a single `PreviewDepositBatch`
+    /// query is sent if the vault lists the BatchPreview extension in its
+    /// vault standard info. Otherwise one `PreviewDeposit` query is sent per
+    /// amount.
+    pub fn query_preview_deposit_batch(
+        &self,
+        querier: &QuerierWrapper,
+        amounts: &[Uint128],
+    ) -> StdResult<Vec<Uint128>>
+    where
+        Q: QueryExtension,
+    {
+        #[cfg(feature = "batch-preview")]
+        if self.supports_batch_preview(querier)? {
+            return self.query_batch_preview(
+                querier,
+                BatchPreviewQueryMsg::PreviewDepositBatch {
+                    amounts: amounts.to_vec(),
+                },
+                amounts.len(),
+            );
+        }
+        amounts
+            .iter()
+            .map(|amount| self.query_preview_deposit(querier, *amount))
+            .collect()
+    }
+
+    /// Queries the vault for a preview of a redeem of each of `amounts`. The
+    /// returned amounts of base tokens are in the same order as `amounts`.
+    ///
+    /// With the `batch-preview` feature enabled, a single `PreviewRedeemBatch`
+    /// query is sent if the vault lists the BatchPreview extension in its
+    /// vault standard info. Otherwise one `PreviewRedeem` query is sent per
+    /// amount.
+    pub fn query_preview_redeem_batch(
+        &self,
+        querier: &QuerierWrapper,
+        amounts: &[Uint128],
+    ) -> StdResult<Vec<Uint128>>
+    where
+        Q: QueryExtension,
+    {
+        #[cfg(feature = "batch-preview")]
+        if self.supports_batch_preview(querier)? {
+            return self.query_batch_preview(
+                querier,
+                BatchPreviewQueryMsg::PreviewRedeemBatch {
+                    amounts: amounts.to_vec(),
+                },
+                amounts.len(),
+            );
+        }
+        amounts
+            .iter()
+            .map(|amount| self.query_preview_redeem(querier, *amount))
+            .collect()
+    }
+
+    /// Returns whether the vault lists the BatchPreview extension in its vault
+    /// standard info.
+    #[cfg(feature = "batch-preview")]
+    fn supports_batch_preview(&self, querier: &QuerierWrapper) -> StdResult<bool>
+    where
+        Q: QueryExtension,
+    {
+        Ok(self
+            .query_vault_standard_info(querier)?
+            .extensions
+            .iter()
+            .any(|ext| ext == BATCH_PREVIEW_EXTENSION_NAME))
+    }
+
+    /// Sends a BatchPreview query and checks that the vault returned one
+    /// result per amount.
+    #[cfg(feature = "batch-preview")]
+    fn query_batch_preview(
+        &self,
+        querier: &QuerierWrapper,
+        msg: BatchPreviewQueryMsg,
+        len: usize,
+    ) -> StdResult<Vec<Uint128>> {
+        let results: Vec<Uint128> = querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::BatchPreview(msg)),
+        )?;
+        if results.len() != len {
+            return Err(StdError::generic_err(format!(
+                "Vault returned {} batch preview results for {len} amounts",
+                results.len()
+            )));
+        }
+        Ok(results)
+    }
+
     /// Queries the vault for the total assets held in the vault
-    pub fn query_total_assets(&self, querier: &QuerierWrapper) -> StdResult<Uint128> {
+    pub fn query_total_assets(&self, querier: &QuerierWrapper) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
         querier.query_wasm_smart(&self.addr, &VaultStandardQueryMsg::<Q>::TotalAssets {})
     }
 
     /// Queries the vault for the total vault token supply
-    pub fn query_total_vault_token_supply(&self, querier: &QuerierWrapper) -> StdResult<Uint128> {
+    pub fn query_total_vault_token_supply(&self, querier: &QuerierWrapper) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
         querier.query_wasm_smart(
             &self.addr,
             &VaultStandardQueryMsg::<Q>::TotalVaultTokenSupply {},
@@ -177,7 +587,10 @@ where
         &self,
         querier: &QuerierWrapper,
         amount: impl Into<Uint128>,
-    ) -> StdResult<Uint128> {
+    ) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
         querier.query_wasm_smart(
             &self.addr,
             &VaultStandardQueryMsg::<Q>::ConvertToShares {
@@ -191,7 +604,10 @@ where
         &self,
         querier: &QuerierWrapper,
         amount: impl Into<Uint128>,
-    ) -> StdResult<Uint128> {
+    ) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
         querier.query_wasm_smart(
             &self.addr,
             &VaultStandardQueryMsg::<Q>::ConvertToAssets {
@@ -199,4 +615,1402 @@ where
             },
         )
     }
+
+    /// Simulates depositing `amount` base tokens and immediately redeeming the
+    /// received vault tokens, using the `PreviewDeposit` and `PreviewRedeem`
+    /// queries. Returns the implied entry plus exit cost in basis points,
+    /// rounded up, or zero if the round trip does not lose any base tokens.
+    pub fn query_round_trip(
+        &self,
+        querier: &QuerierWrapper,
+        amount: impl Into<Uint128>,
+    ) -> StdResult<u64>
+    where
+        Q: QueryExtension,
+    {
+        let amount = amount.into();
+        if amount.is_zero() {
+            return Err(StdError::generic_err("Round trip amount must be non-zero"));
+        }
+        let shares = self.query_preview_deposit(querier, amount)?;
+        let assets_out = self.query_preview_redeem(querier, shares)?;
+        let loss = amount.saturating_sub(assets_out);
+        let amount = Uint256::from(amount);
+        let cost_bps = (loss.full_mul(10_000u128) + amount - Uint256::one()) / amount;
+        Ok(Uint128::try_from(cost_bps)?.u128() as u64)
+    }
+
+    /// Queries the vault for its total assets and total vault token supply and
+    /// returns the price of one vault token, denominated in base tokens. If no
+    /// vault tokens have been minted, the price is one, matching the 1:1
+    /// conversion of [`math::convert_to_shares`]. Returns an error if the price
+    /// does not fit in a [`Decimal`].
+    pub fn price_per_share(&self, querier: &QuerierWrapper) -> StdResult<Decimal>
+    where
+        Q: QueryExtension,
+    {
+        let total_assets = self.query_total_assets(querier)?;
+        let total_supply = self.query_total_vault_token_supply(querier)?;
+        if total_supply.is_zero() {
+            return Ok(Decimal::one());
+        }
+        Decimal::checked_from_ratio(total_assets, total_supply)
+            .map_err(|e| StdError::generic_err(e.to_string()))
+    }
+
+    /// Returns the value of `shares` vault tokens in base tokens, rounded
+    /// down, computed from the total assets and total vault token supply of
+    /// the vault. If no vault tokens have been minted, vault tokens are valued
+    /// 1:1.
+    pub fn value_of_shares(
+        &self,
+        querier: &QuerierWrapper,
+        shares: impl Into<Uint128>,
+    ) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
+        math::convert_to_assets(
+            shares.into(),
+            self.query_total_assets(querier)?,
+            self.query_total_vault_token_supply(querier)?,
+            Rounding::Down,
+        )
+    }
+
+    /// Returns the amount of vault tokens worth `assets` base tokens, rounded
+    /// down, computed from the total assets and total vault token supply of
+    /// the vault. If no vault tokens have been minted, base tokens are
+    /// converted 1:1. Returns an error if vault tokens have been minted but
+    /// the vault holds no assets.
+    pub fn shares_for_value(
+        &self,
+        querier: &QuerierWrapper,
+        assets: impl Into<Uint128>,
+    ) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
+        math::convert_to_shares(
+            assets.into(),
+            self.query_total_assets(querier)?,
+            self.query_total_vault_token_supply(querier)?,
+            Rounding::Down,
+        )
+    }
+
+    /// Queries the vault for its info, vault standard info, total assets and
+    /// total vault token supply, and computes the price per vault token. The
+    /// price is `None` if no vault tokens have been minted, see
+    /// [`VaultState::share_price`]. Returns an error if the price does not fit
+    /// in a [`Decimal`].
+    pub fn query_vault_state(&self, querier: &QuerierWrapper) -> StdResult<VaultState>
+    where
+        Q: QueryExtension,
+    {
+        let info = self.query_vault_info(querier)?;
+        let standard_info = self.query_vault_standard_info(querier)?;
+        let total_assets = self.query_total_assets(querier)?;
+        let total_vault_token_supply = self.query_total_vault_token_supply(querier)?;
+        let share_price = if total_vault_token_supply.is_zero() {
+            None
+        } else {
+            Some(
+                Decimal::checked_from_ratio(total_assets, total_vault_token_supply)
+                    .map_err(|e| StdError::generic_err(e.to_string()))?,
+            )
+        };
+
+        Ok(VaultState {
+            info,
+            standard_info,
+            total_assets,
+            total_vault_token_supply,
+            share_price,
+        })
+    }
+}
+
+/// A snapshot of the state of a vault, as returned by
+/// [`VaultContract::query_vault_state`].
+#[cw_serde]
+pub struct VaultState {
+    /// The vault info.
+    pub info: VaultInfoResponse,
+    /// The vault standard info.
+    pub standard_info: VaultStandardInfoResponse,
+    /// The total assets held in the vault, denominated in base tokens.
+    pub total_assets: Uint128,
+    /// The total supply of vault tokens.
+    pub total_vault_token_supply: Uint128,
+    /// The price of one vault token, denominated in base tokens, or `None` if
+    /// no vault tokens have been minted. Unlike
+    /// [`VaultContract::price_per_share`], which returns one in that case to
+    /// match the 1:1 conversion of [`math::convert_to_shares`], this keeps the
+    /// empty vault distinguishable from a vault trading at par.
+    pub share_price: Option<Decimal>,
+}
+
+impl<E, Q> From<Addr> for VaultContract<E, Q> {
+    fn from(addr: Addr) -> Self {
+        Self {
+            addr,
+            execute_msg_extension: PhantomData,
+            query_msg_extension: PhantomData,
+        }
+    }
+}
+
+impl<E, Q> TryFrom<(&str, &dyn Api)> for VaultContract<E, Q> {
+    type Error = StdError;
+
+    /// Validates the address against the api.
+    fn try_from((addr, api): (&str, &dyn Api)) -> StdResult<Self> {
+        VaultContractUnchecked::from(addr).check(api)
+    }
+}
+
+impl<E, Q> AsRef<Addr> for VaultContract<E, Q> {
+    fn as_ref(&self) -> &Addr {
+        &self.addr
+    }
+}
+
+impl<E, Q> fmt::Display for VaultContract<E, Q> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.addr)
+    }
+}
+
+/// A type-erased version of [`VaultContract`] without the generic extension
+/// parameters, which is convenient to store in contract state or to hold
+/// vaults with different extensions in one collection. Convert it into a
+/// [`VaultContract`] with [`AnyVaultContract::into_typed`] to interact with
+/// the vault.
+#[cw_serde]
+pub struct AnyVaultContract {
+    /// The address of the vault contract.
+    pub addr: Addr,
+}
+
+impl AnyVaultContract {
+    /// Create a new AnyVaultContract instance.
+    pub fn new(addr: &Addr) -> Self {
+        Self { addr: addr.clone() }
+    }
+
+    /// Convert into a [`VaultContract`] with the given extension enums.
+    pub fn into_typed<E, Q>(self) -> VaultContract<E, Q> {
+        VaultContract::new(&self.addr)
+    }
+}
+
+impl<E, Q> From<VaultContract<E, Q>> for AnyVaultContract {
+    fn from(vault: VaultContract<E, Q>) -> Self {
+        Self { addr: vault.addr }
+    }
+}
+
+impl<E, Q> From<&VaultContract<E, Q>> for AnyVaultContract {
+    fn from(vault: &VaultContract<E, Q>) -> Self {
+        Self {
+            addr: vault.addr.clone(),
+        }
+    }
+}
+
+/// A wrapper around a [`VaultContract`] that caches the results of the
+/// `TotalAssets` and `TotalVaultTokenSupply` queries, for contracts that need
+/// to value many positions in the same vault within a single execution. The
+/// cache lives as long as the wrapper, so a new wrapper should be created for
+/// each call context.
+///
+/// Conversions are computed from the cached totals, rounding down, instead of
+/// querying `ConvertToAssets` and `ConvertToShares`. They may therefore differ
+/// slightly from the vault's own conversion if it uses e.g. virtual offsets.
+pub struct CachedVaultQuerier<'a, E = ExtensionExecuteMsg, Q = ExtensionQueryMsg> {
+    vault: &'a VaultContract<E, Q>,
+    querier: QuerierWrapper<'a>,
+    total_assets: Cell<Option<Uint128>>,
+    total_vault_token_supply: Cell<Option<Uint128>>,
+    vault_standard_info: OnceCell<VaultStandardInfoResponse>,
+}
+
+impl<'a, E, Q> CachedVaultQuerier<'a, E, Q>
+where
+    Q: QueryExtension,
+{
+    /// Create a new CachedVaultQuerier instance with an empty cache.
+    pub fn new(vault: &'a VaultContract<E, Q>, querier: QuerierWrapper<'a>) -> Self {
+        Self {
+            vault,
+            querier,
+            total_assets: Cell::new(None),
+            total_vault_token_supply: Cell::new(None),
+            vault_standard_info: OnceCell::new(),
+        }
+    }
+
+    /// Returns the total assets held in the vault, querying the vault only
+    /// on the first call.
+    pub fn total_assets(&self) -> StdResult<Uint128> {
+        if let Some(total_assets) = self.total_assets.get() {
+            return Ok(total_assets);
+        }
+        let total_assets = self.vault.query_total_assets(&self.querier)?;
+        self.total_assets.set(Some(total_assets));
+        Ok(total_assets)
+    }
+
+    /// Returns the total vault token supply, querying the vault only on the
+    /// first call.
+    pub fn total_vault_token_supply(&self) -> StdResult<Uint128> {
+        if let Some(supply) = self.total_vault_token_supply.get() {
+            return Ok(supply);
+        }
+        let supply = self.vault.query_total_vault_token_supply(&self.querier)?;
+        self.total_vault_token_supply.set(Some(supply));
+        Ok(supply)
+    }
+
+    /// Converts an amount of vault tokens to base tokens using the cached
+    /// totals, rounding down. If no vault tokens have been minted, vault tokens
+    /// are converted 1:1, see [`math::convert_to_assets`].
+    pub fn convert_to_assets(&self, shares: impl Into<Uint128>) -> StdResult<Uint128> {
+        math::convert_to_assets(
+            shares.into(),
+            self.total_assets()?,
+            self.total_vault_token_supply()?,
+            Rounding::Down,
+        )
+    }
+
+    /// Converts an amount of base tokens to vault tokens using the cached
+    /// totals, rounding down. If no vault tokens have been minted, base tokens
+    /// are converted 1:1. Returns an error if vault tokens have been minted but
+    /// the vault holds no assets, see [`math::convert_to_shares`].
+    pub fn convert_to_shares(&self, assets: impl Into<Uint128>) -> StdResult<Uint128> {
+        math::convert_to_shares(
+            assets.into(),
+            self.total_assets()?,
+            self.total_vault_token_supply()?,
+            Rounding::Down,
+        )
+    }
+
+    /// Clears the cached totals, so that the next call queries the vault
+    /// again, e.g. after a deposit or redeem has been executed. The cached
+    /// vault standard info is kept.
+    pub fn clear(&self) {
+        self.total_assets.set(None);
+        self.total_vault_token_supply.set(None);
+    }
+
+    /// Returns the vault standard info, querying the vault only on the first
+    /// call.
+    pub fn vault_standard_info(&self) -> StdResult<&VaultStandardInfoResponse> {
+        if let Some(info) = self.vault_standard_info.get() {
+            return Ok(info);
+        }
+        let info = self.vault.query_vault_standard_info(&self.querier)?;
+        Ok(self.vault_standard_info.get_or_init(|| info))
+    }
+
+    /// Returns whether the vault lists the extension `name` in its vault
+    /// standard info.
+    pub fn supports_extension(&self, name: &str) -> StdResult<bool> {
+        Ok(self
+            .vault_standard_info()?
+            .extensions
+            .iter()
+            .any(|ext| ext == name))
+    }
+}
+
+#[cfg(feature = "lockup")]
+#[cfg_attr(docsrs, doc(cfg(feature = "lockup")))]
+impl<'a, E, Q> CachedVaultQuerier<'a, E, Q>
+where
+    Q: QueryExtension,
+{
+    /// Queries the lockup duration of the vault, or returns `None` if the
+    /// vault does not implement the lockup extension.
+    pub fn try_query_lockup_duration(&self) -> StdResult<Option<Duration>> {
+        if !self.supports_extension(LOCKUP_EXTENSION_NAME)? {
+            return Ok(None);
+        }
+        self.vault.query_lockup_duration(&self.querier).map(Some)
+    }
+
+    /// Queries the unlocking positions of an owner, or returns `None` if the
+    /// vault does not implement the lockup extension.
+    pub fn try_query_lockups(
+        &self,
+        owner: impl Into<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Option<Vec<UnlockingPosition>>> {
+        if !self.supports_extension(LOCKUP_EXTENSION_NAME)? {
+            return Ok(None);
+        }
+        self.vault
+            .query_lockups(&self.querier, owner, start_after, limit)
+            .map(Some)
+    }
+
+    /// Queries a specific unlocking position, or returns `None` if the vault
+    /// does not implement the lockup extension.
+    pub fn try_query_lockup(&self, lockup_id: u64) -> StdResult<Option<UnlockingPosition>> {
+        if !self.supports_extension(LOCKUP_EXTENSION_NAME)? {
+            return Ok(None);
+        }
+        self.vault.query_lockup(&self.querier, lockup_id).map(Some)
+    }
+}
+
+#[cfg(feature = "force-unlock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "force-unlock")))]
+impl<'a, E, Q> CachedVaultQuerier<'a, E, Q>
+where
+    Q: QueryExtension,
+{
+    /// Queries whether an address is whitelisted to force withdraw, or
+    /// returns `None` if the vault does not implement the force unlock
+    /// extension.
+    pub fn try_query_is_force_withdraw_whitelisted(
+        &self,
+        address: impl Into<String>,
+    ) -> StdResult<Option<bool>> {
+        if !self.supports_extension(FORCE_UNLOCK_EXTENSION_NAME)? {
+            return Ok(None);
+        }
+        self.vault
+            .query_is_force_withdraw_whitelisted(&self.querier, address)
+            .map(Some)
+    }
+}
+
+#[cfg(feature = "cw20")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cw20")))]
+impl<E, Q> VaultContract<E, Q> {
+    /// Returns a CosmosMsg to deposit cw20 base tokens into the vault by
+    /// sending them to the vault with a `Cw20ExecuteMsg::Send`, with the
+    /// vault's `Deposit` message as hook message. Unlike [`Self::deposit_cw20`]
+    /// this does not require approving spend for the cw20 tokens first.
+    /// Returns an error if `amount` is zero or `recipient` is the vault itself.
+    pub fn deposit_cw20_via_hook<C>(
+        &self,
+        cw20_addr: impl Into<String>,
+        amount: impl Into<Uint128>,
+        recipient: Option<String>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        let amount = amount.into();
+        validate_msg(&self.addr, amount, &recipient)?;
+        Ok(WasmMsg::Execute {
+            contract_addr: cw20_addr.into(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                contract: self.addr.to_string(),
+                amount,
+                msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Deposit { amount, recipient })?,
+            })?,
+            funds: vec![],
+        }
+        .into())
+    }
+
+    /// Queries the vault info to determine whether the base token is a native
+    /// or a cw20 token, and returns the messages to deposit `amount` base
+    /// tokens into the vault. Native base tokens are attached as funds, while
+    /// cw20 base tokens are deposited using the given `cw20_method`.
+    ///
+    /// The base token is considered a native denom if it is not a valid
+    /// address. Otherwise it must be a cw20 token, which is checked with the
+    /// cw20 `TokenInfo` query, and any error of that query is returned.
+    pub fn deposit_any<C>(
+        &self,
+        api: &dyn Api,
+        querier: &QuerierWrapper,
+        amount: impl Into<Uint128>,
+        recipient: Option<String>,
+        cw20_method: Cw20DepositMethod,
+    ) -> Result<Vec<CosmosMsg<C>>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+        Q: QueryExtension,
+    {
+        let amount = amount.into();
+        validate_msg(&self.addr, amount, &recipient)?;
+        let base_token = self.query_vault_info(querier)?.base_token;
+        if api.addr_validate(&base_token).is_err() {
+            return Ok(vec![self.deposit(amount, &base_token, recipient)?]);
+        }
+        querier.query_wasm_smart::<TokenInfoResponse>(&base_token, &Cw20QueryMsg::TokenInfo {})?;
+
+        match cw20_method {
+            Cw20DepositMethod::Allowance => Ok(vec![
+                WasmMsg::Execute {
+                    contract_addr: base_token,
+                    msg: to_json_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                        spender: self.addr.to_string(),
+                        amount,
+                        expires: None,
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                self.deposit_cw20(amount, recipient)?,
+            ]),
+            Cw20DepositMethod::SendHook => Ok(vec![
+                self.deposit_cw20_via_hook(base_token, amount, recipient)?
+            ]),
+        }
+    }
+}
+
+/// The method used by [`VaultContract::deposit_any`] to deposit cw20 base
+/// tokens.
+#[cfg(feature = "cw20")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cw20")))]
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Cw20DepositMethod {
+    /// Approve spend of the base tokens by the vault with
+    /// `Cw20ExecuteMsg::IncreaseAllowance`, followed by a `Deposit` message.
+    Allowance,
+    /// Send the base tokens to the vault with a `Cw20ExecuteMsg::Send`, with
+    /// the `Deposit` message as hook message.
+    SendHook,
+}
+
+#[cfg(feature = "cw-asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cw-asset")))]
+impl<E, Q> VaultContract<E, Q> {
+    /// Returns a CosmosMsg to deposit a `cw_asset::Asset` into the vault.
+    /// Native assets are attached as funds, while cw20 assets are sent to the
+    /// vault with a `Cw20ExecuteMsg::Send` hook, see
+    /// [`Self::deposit_cw20_via_hook`].
+    pub fn deposit_asset<C>(
+        &self,
+        asset: &Asset,
+        recipient: Option<String>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        match &asset.info {
+            AssetInfo::Native(denom) => self.deposit(asset.amount, denom, recipient),
+            AssetInfo::Cw20(addr) => self.deposit_cw20_via_hook(addr, asset.amount, recipient),
+            info => Err(StdError::generic_err(format!(
+                "Unsupported asset type for deposit: {info}"
+            ))
+            .into()),
+        }
+    }
+}
+
+#[cfg(feature = "bank-supply")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bank-supply")))]
+impl<E, Q> VaultContract<E, Q> {
+    /// Queries the supply of the native vault token from the bank module,
+    /// without trusting the vault, and cross-checks it against the
+    /// `TotalVaultTokenSupply` reported by the vault. The vault token denom is
+    /// resolved from the `Info` query. Returns the bank supply, or a
+    /// [`VaultStandardError::SupplyMismatch`] error if the two differ.
+    ///
+    /// Must only be used for vaults with a native vault token.
+    pub fn query_native_vault_token_supply(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> Result<Uint128, VaultStandardError>
+    where
+        Q: QueryExtension,
+    {
+        let vault_token = self.query_vault_info(querier)?.vault_token;
+        let bank_supply = querier.query_supply(vault_token)?.amount;
+        let reported_supply = self.query_total_vault_token_supply(querier)?;
+        if bank_supply != reported_supply {
+            return Err(VaultStandardError::SupplyMismatch {
+                bank_supply,
+                reported_supply,
+            });
+        }
+        Ok(bank_supply)
+    }
+}
+
+#[cfg(feature = "lockup")]
+#[cfg_attr(docsrs, doc(cfg(feature = "lockup")))]
+impl<E, Q> VaultContract<E, Q> {
+    /// Returns a CosmosMsg to start unlocking vault tokens, creating an
+    /// unlocking position. Returns an error if `amount` is zero.
+    pub fn unlock<C>(
+        &self,
+        amount: impl Into<Uint128>,
+        vault_token_denom: &str,
+    ) -> Result<CosmosMsg<C>, VaultStandardError> {
+        let amount = amount.into();
+        validate_msg(&self.addr, amount, &None)?;
+        Ok(LockupExecuteMsg::Unlock { amount }.into_cosmos_msg(
+            self.addr.to_string(),
+            vec![coin(amount.u128(), vault_token_denom)],
+        )?)
+    }
+
+    /// Returns a SubMsg to start unlocking vault tokens, with a reply on
+    /// success with the given `reply_id`. The id of the created unlocking
+    /// position can be parsed from the reply with
+    /// [`unlock_lockup_id_from_reply`](crate::parse::unlock_lockup_id_from_reply).
+    pub fn unlock_submsg<C>(
+        &self,
+        amount: impl Into<Uint128>,
+        vault_token_denom: &str,
+        reply_id: u64,
+    ) -> Result<SubMsg<C>, VaultStandardError> {
+        Ok(SubMsg::reply_on_success(
+            self.unlock(amount, vault_token_denom)?,
+            reply_id,
+        ))
+    }
+
+    /// Queries the vault for the unlocking positions of an owner
+    pub fn query_lockups(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<UnlockingPosition>> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::Lockup(
+                LockupQueryMsg::UnlockingPositions {
+                    owner: owner.into(),
+                    start_after,
+                    limit,
+                },
+            )),
+        )
+    }
+
+    /// Queries the vault for a specific unlocking position
+    pub fn query_lockup(
+        &self,
+        querier: &QuerierWrapper,
+        lockup_id: u64,
+    ) -> StdResult<UnlockingPosition> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::Lockup(
+                LockupQueryMsg::UnlockingPosition { lockup_id },
+            )),
+        )
+    }
+
+    /// Queries the vault for the duration of its lockup
+    pub fn query_lockup_duration(&self, querier: &QuerierWrapper) -> StdResult<Duration> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::Lockup(
+                LockupQueryMsg::LockupDuration {},
+            )),
+        )
+    }
+}
+
+#[cfg(feature = "force-unlock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "force-unlock")))]
+impl<E, Q> VaultContract<E, Q> {
+    /// Returns a CosmosMsg to force redeem vault tokens from the vault,
+    /// bypassing the lockup. Only callable by whitelisted addresses. Returns an
+    /// error if `amount` is zero or `recipient` is the vault itself.
+    pub fn force_withdraw<C>(
+        &self,
+        amount: impl Into<Uint128>,
+        vault_token_denom: &str,
+        recipient: Option<String>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError> {
+        let amount = amount.into();
+        validate_msg(&self.addr, amount, &recipient)?;
+        Ok(
+            ForceUnlockExecuteMsg::ForceRedeem { recipient, amount }.into_cosmos_msg(
+                self.addr.to_string(),
+                vec![coin(amount.u128(), vault_token_denom)],
+            )?,
+        )
+    }
+
+    /// Returns a CosmosMsg to force withdraw base tokens from an unlocking
+    /// position before it has finished unlocking. Only callable by whitelisted
+    /// addresses. If `amount` is `None`, the entire position is withdrawn.
+    /// Returns an error if `amount` is zero or `recipient` is the vault itself.
+    pub fn force_withdraw_unlocking<C>(
+        &self,
+        lockup_id: u64,
+        amount: Option<impl Into<Uint128>>,
+        recipient: Option<String>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError> {
+        let amount = amount.map(Into::into);
+        match amount {
+            Some(amount) => validate_msg(&self.addr, amount, &recipient)?,
+            None => validate_recipient(&self.addr, &recipient)?,
+        }
+        Ok(ForceUnlockExecuteMsg::ForceWithdrawUnlocking {
+            lockup_id,
+            amount,
+            recipient,
+        }
+        .into_cosmos_msg(self.addr.to_string(), vec![])?)
+    }
+
+    /// Returns a SubMsg force redeeming `shares` vault tokens held by
+    /// `liquidator`, with a reply on success with the given `reply_id`,
+    /// together with the minimum amount of base tokens the liquidation is
+    /// expected to return.
+    ///
+    /// `liquidator` must be the address sending the message, e.g. the
+    /// liquidating contract, and must be whitelisted to force withdraw. The
+    /// expected proceeds are queried with `PreviewRedeem` and reduced by
+    /// `max_haircut`, the fraction of the preview the caller is willing to lose
+    /// to price movements or fees. The returned minimum is rounded down by the
+    /// `Decimal` multiplication and should be checked against the base tokens
+    /// actually received in the reply.
+    pub fn build_liquidation<C>(
+        &self,
+        querier: &QuerierWrapper,
+        liquidator: &Addr,
+        shares: impl Into<Uint128>,
+        max_haircut: Decimal,
+        recipient: Option<String>,
+        reply_id: u64,
+    ) -> Result<(SubMsg<C>, Uint128), VaultStandardError>
+    where
+        Q: QueryExtension,
+    {
+        let shares = shares.into();
+        validate_msg(&self.addr, shares, &recipient)?;
+        if max_haircut > Decimal::one() {
+            return Err(VaultStandardError::InvalidHaircut {
+                haircut: max_haircut,
+            });
+        }
+        if !self.query_is_force_withdraw_whitelisted(querier, liquidator)? {
+            return Err(VaultStandardError::NotForceWithdrawWhitelisted {
+                address: liquidator.clone(),
+            });
+        }
+
+        let expected = self.query_preview_redeem(querier, shares)?;
+        let min_out = expected * (Decimal::one() - max_haircut);
+        let vault_token = self.query_vault_info(querier)?.vault_token;
+        let msg = self.force_withdraw(shares, &vault_token, recipient)?;
+
+        Ok((SubMsg::reply_on_success(msg, reply_id), min_out))
+    }
+
+    /// Queries the vault for the addresses whitelisted to force withdraw
+    pub fn query_force_withdraw_whitelist(
+        &self,
+        querier: &QuerierWrapper,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<Addr>> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::ForceUnlock(
+                ForceUnlockQueryMsg::ForceWithdrawWhitelist { start_after, limit },
+            )),
+        )
+    }
+
+    /// Queries the vault for whether an address is whitelisted to force
+    /// withdraw
+    pub fn query_is_force_withdraw_whitelisted(
+        &self,
+        querier: &QuerierWrapper,
+        address: impl Into<String>,
+    ) -> StdResult<bool> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::ForceUnlock(
+                ForceUnlockQueryMsg::IsForceWithdrawWhitelisted {
+                    address: address.into(),
+                },
+            )),
+        )
+    }
+}
+
+#[cfg(feature = "multi-asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "multi-asset")))]
+impl<E, Q> VaultContract<E, Q> {
+    /// Returns a CosmosMsg to deposit multiple assets into the vault. The
+    /// assets are attached as funds, sorted by denom as required by the bank
+    /// module. Returns an error if `assets` is empty, contains a zero amount or
+    /// the same denom more than once, or if `recipient` is the vault itself.
+    pub fn deposit_multiple<C>(
+        &self,
+        assets: Vec<Coin>,
+        recipient: Option<String>,
+        min_shares_out: Option<Uint128>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError> {
+        if assets.is_empty() {
+            return Err(VaultStandardError::EmptyAssets {});
+        }
+        for asset in &assets {
+            validate_msg(&self.addr, asset.amount, &recipient)?;
+        }
+        let mut funds = assets.clone();
+        funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+        if let Some(pair) = funds.windows(2).find(|pair| pair[0].denom == pair[1].denom) {
+            return Err(VaultStandardError::DuplicateDenom {
+                denom: pair[0].denom.clone(),
+            });
+        }
+        Ok(MultiAssetExecuteMsg::DepositMultiple {
+            assets,
+            recipient,
+            min_shares_out,
+        }
+        .into_cosmos_msg(self.addr.to_string(), funds)?)
+    }
+
+    /// Queries the vault for a preview of a multi asset deposit
+    pub fn query_preview_deposit_multiple(
+        &self,
+        querier: &QuerierWrapper,
+        assets: Vec<Coin>,
+    ) -> StdResult<Uint128> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::MultiAsset(
+                MultiAssetQueryMsg::PreviewDepositMultiple { assets },
+            )),
+        )
+    }
+
+    /// Queries the vault for the denoms that can be deposited via
+    /// `DepositMultiple`
+    pub fn query_depositable_assets(&self, querier: &QuerierWrapper) -> StdResult<Vec<String>> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::MultiAsset(
+                MultiAssetQueryMsg::DepositableAssets {},
+            )),
+        )
+    }
+}
+
+/// A helper struct to interact with a vault contract that implements the
+/// Cw4626 extension, i.e. a vault where the vault token is a cw20 token
+/// managed by the vault contract itself.
+#[cfg(feature = "cw4626")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cw4626")))]
+#[cw_serde]
+#[schemars(rename = "Cw4626Contract", bound = "")]
+pub struct Cw4626Contract<E = ExtensionExecuteMsg, Q = ExtensionQueryMsg> {
+    /// The address of the vault contract, which is also the address of the
+    /// vault token.
+    pub addr: Addr,
+    /// The extension enum for ExecuteMsg variants.
+    #[serde(default, skip_serializing)]
+    #[schemars(skip)]
+    execute_msg_extension: PhantomData<E>,
+    /// The extension enum for QueryMsg variants.
+    #[serde(default, skip_serializing)]
+    #[schemars(skip)]
+    query_msg_extension: PhantomData<Q>,
+}
+
+#[cfg(feature = "cw4626")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cw4626")))]
+impl<E, Q> Cw4626Contract<E, Q> {
+    /// Create a new Cw4626Contract instance.
+    pub fn new(addr: &Addr) -> Self {
+        Self {
+            addr: addr.clone(),
+            execute_msg_extension: PhantomData,
+            query_msg_extension: PhantomData,
+        }
+    }
+
+    fn execute<C>(
+        &self,
+        msg: &Cw4626ExecuteMsg<E>,
+        funds: Vec<Coin>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr.to_string(),
+            msg: to_json_binary(msg)?,
+            funds,
+        }
+        .into())
+    }
+
+    fn query<T: DeserializeOwned>(
+        &self,
+        querier: &QuerierWrapper,
+        msg: &Cw4626QueryMsg<Q>,
+    ) -> StdResult<T>
+    where
+        Q: QueryExtension,
+    {
+        querier.query_wasm_smart(&self.addr, msg)
+    }
+
+    /// Returns a CosmosMsg to deposit native base tokens into the vault.
+    /// Returns an error if `amount` is zero or `recipient` is the vault itself.
+    pub fn deposit<C>(
+        &self,
+        amount: impl Into<Uint128>,
+        base_denom: &str,
+        recipient: Option<String>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        let amount = amount.into();
+        validate_msg(&self.addr, amount, &recipient)?;
+        self.execute(
+            &Cw4626ExecuteMsg::Deposit { amount, recipient },
+            vec![coin(amount.u128(), base_denom)],
+        )
+    }
+
+    /// Returns a CosmosMsg to deposit cw20 base tokens into the vault, leaving
+    /// the native funds field empty. The caller should have approved spend for
+    /// the cw20 tokens first. Returns an error if `amount` is zero or
+    /// `recipient` is the vault itself.
+    pub fn deposit_cw20<C>(
+        &self,
+        amount: Uint128,
+        recipient: Option<String>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        validate_msg(&self.addr, amount, &recipient)?;
+        self.execute(&Cw4626ExecuteMsg::Deposit { amount, recipient }, vec![])
+    }
+
+    /// Returns a CosmosMsg to redeem vault tokens from the vault. No funds are
+    /// attached, as the vault burns the vault tokens directly from the
+    /// balance of the caller. Returns an error if `amount` is zero or
+    /// `recipient` is the vault itself.
+    pub fn redeem<C>(
+        &self,
+        amount: impl Into<Uint128>,
+        recipient: Option<String>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        let amount = amount.into();
+        validate_msg(&self.addr, amount, &recipient)?;
+        self.execute(&Cw4626ExecuteMsg::Redeem { amount, recipient }, vec![])
+    }
+
+    /// Returns a CosmosMsg to transfer vault tokens to another address.
+    pub fn transfer<C>(
+        &self,
+        recipient: impl Into<String>,
+        amount: impl Into<Uint128>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        self.execute(
+            &Cw4626ExecuteMsg::Transfer {
+                recipient: recipient.into(),
+                amount: amount.into(),
+            },
+            vec![],
+        )
+    }
+
+    /// Returns a CosmosMsg to send vault tokens to a contract, triggering
+    /// `msg` on the receiving contract.
+    pub fn send<C>(
+        &self,
+        contract: impl Into<String>,
+        amount: impl Into<Uint128>,
+        msg: Binary,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        self.execute(
+            &Cw4626ExecuteMsg::Send {
+                contract: contract.into(),
+                amount: amount.into(),
+                msg,
+            },
+            vec![],
+        )
+    }
+
+    /// Returns a CosmosMsg to allow `spender` to spend an additional `amount`
+    /// of the caller's vault tokens, e.g. so that it can redeem them on the
+    /// caller's behalf via [`Self::transfer_from`] or [`Self::send_from`].
+    pub fn increase_allowance<C>(
+        &self,
+        spender: impl Into<String>,
+        amount: impl Into<Uint128>,
+        expires: Option<Expiration>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        self.execute(
+            &Cw4626ExecuteMsg::IncreaseAllowance {
+                spender: spender.into(),
+                amount: amount.into(),
+                expires,
+            },
+            vec![],
+        )
+    }
+
+    /// Returns a CosmosMsg to lower the allowance of `spender` over the
+    /// caller's vault tokens by `amount`.
+    pub fn decrease_allowance<C>(
+        &self,
+        spender: impl Into<String>,
+        amount: impl Into<Uint128>,
+        expires: Option<Expiration>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        self.execute(
+            &Cw4626ExecuteMsg::DecreaseAllowance {
+                spender: spender.into(),
+                amount: amount.into(),
+                expires,
+            },
+            vec![],
+        )
+    }
+
+    /// Returns a CosmosMsg to transfer vault tokens from `owner` to
+    /// `recipient`, using the allowance granted to the caller.
+    pub fn transfer_from<C>(
+        &self,
+        owner: impl Into<String>,
+        recipient: impl Into<String>,
+        amount: impl Into<Uint128>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        self.execute(
+            &Cw4626ExecuteMsg::TransferFrom {
+                owner: owner.into(),
+                recipient: recipient.into(),
+                amount: amount.into(),
+            },
+            vec![],
+        )
+    }
+
+    /// Returns a CosmosMsg to send vault tokens from `owner` to `contract`,
+    /// using the allowance granted to the caller and triggering `msg` on the
+    /// receiving contract.
+    pub fn send_from<C>(
+        &self,
+        owner: impl Into<String>,
+        contract: impl Into<String>,
+        amount: impl Into<Uint128>,
+        msg: Binary,
+    ) -> Result<CosmosMsg<C>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        self.execute(
+            &Cw4626ExecuteMsg::SendFrom {
+                owner: owner.into(),
+                contract: contract.into(),
+                amount: amount.into(),
+                msg,
+            },
+            vec![],
+        )
+    }
+
+    /// Returns the CosmosMsgs to redeem vault tokens owned by `owner` on their
+    /// behalf, using the allowance granted to the caller. The vault tokens are
+    /// first transferred from `owner` to the caller, `contract`, and then
+    /// redeemed, sending the withdrawn base tokens to `recipient`. Returns an
+    /// error if `amount` is zero or `recipient` is the vault itself.
+    pub fn redeem_from<C>(
+        &self,
+        contract: &Addr,
+        owner: impl Into<String>,
+        amount: impl Into<Uint128>,
+        recipient: Option<String>,
+    ) -> Result<Vec<CosmosMsg<C>>, VaultStandardError>
+    where
+        E: ExecuteExtension,
+    {
+        let amount = amount.into();
+        validate_msg(&self.addr, amount, &recipient)?;
+        Ok(vec![
+            self.transfer_from(owner, contract.to_string(), amount)?,
+            self.redeem(amount, recipient)?,
+        ])
+    }
+
+    /// Queries the vault token balance of an address
+    pub fn query_balance(
+        &self,
+        querier: &QuerierWrapper,
+        address: impl Into<String>,
+    ) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
+        let res: BalanceResponse = self.query(
+            querier,
+            &Cw4626QueryMsg::Balance {
+                address: address.into(),
+            },
+        )?;
+        Ok(res.balance)
+    }
+
+    /// Queries the cw20 token info of the vault token
+    pub fn query_token_info(&self, querier: &QuerierWrapper) -> StdResult<TokenInfoResponse>
+    where
+        Q: QueryExtension,
+    {
+        self.query(querier, &Cw4626QueryMsg::TokenInfo {})
+    }
+
+    /// Queries the allowance of `spender` over the vault tokens of `owner`
+    pub fn query_allowance(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+        spender: impl Into<String>,
+    ) -> StdResult<AllowanceResponse>
+    where
+        Q: QueryExtension,
+    {
+        self.query(
+            querier,
+            &Cw4626QueryMsg::Allowance {
+                owner: owner.into(),
+                spender: spender.into(),
+            },
+        )
+    }
+
+    /// Queries the vault for the vault standard info
+    pub fn query_vault_standard_info(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<VaultStandardInfoResponse>
+    where
+        Q: QueryExtension,
+    {
+        self.query(querier, &Cw4626QueryMsg::VaultStandardInfo {})
+    }
+
+    /// Queries the vault for the vault info
+    pub fn query_vault_info(&self, querier: &QuerierWrapper) -> StdResult<VaultInfoResponse>
+    where
+        Q: QueryExtension,
+    {
+        self.query(querier, &Cw4626QueryMsg::Info {})
+    }
+
+    /// Queries the vault for a preview of a deposit
+    pub fn query_preview_deposit(
+        &self,
+        querier: &QuerierWrapper,
+        amount: impl Into<Uint128>,
+    ) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
+        self.query(
+            querier,
+            &Cw4626QueryMsg::PreviewDeposit {
+                amount: amount.into(),
+            },
+        )
+    }
+
+    /// Queries the vault for a preview of a redeem
+    pub fn query_preview_redeem(
+        &self,
+        querier: &QuerierWrapper,
+        amount: impl Into<Uint128>,
+    ) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
+        self.query(
+            querier,
+            &Cw4626QueryMsg::PreviewRedeem {
+                amount: amount.into(),
+            },
+        )
+    }
+
+    /// Queries the vault for the total assets held in the vault
+    pub fn query_total_assets(&self, querier: &QuerierWrapper) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
+        self.query(querier, &Cw4626QueryMsg::TotalAssets {})
+    }
+
+    /// Queries the vault for the total vault token supply
+    pub fn query_total_vault_token_supply(&self, querier: &QuerierWrapper) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
+        self.query(querier, &Cw4626QueryMsg::TotalVaultTokenSupply {})
+    }
+
+    /// Queries the vault to convert an amount of base tokens to vault tokens
+    pub fn query_convert_to_shares(
+        &self,
+        querier: &QuerierWrapper,
+        amount: impl Into<Uint128>,
+    ) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
+        self.query(
+            querier,
+            &Cw4626QueryMsg::ConvertToShares {
+                amount: amount.into(),
+            },
+        )
+    }
+
+    /// Queries the vault to convert an amount of vault tokens to base tokens
+    pub fn query_convert_to_assets(
+        &self,
+        querier: &QuerierWrapper,
+        amount: impl Into<Uint128>,
+    ) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
+        self.query(
+            querier,
+            &Cw4626QueryMsg::ConvertToAssets {
+                amount: amount.into(),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockApi;
+    #[cfg(any(feature = "cw20", feature = "batch-preview"))]
+    use cosmwasm_std::{
+        from_json, testing::MockQuerier, ContractResult, Empty, SystemResult, WasmQuery,
+    };
+
+    use super::*;
+    #[cfg(feature = "batch-preview")]
+    use crate::extensions::batch_preview::BatchPreviewQueryMsg;
+
+    #[cfg(feature = "cw20")]
+    const IBC_DENOM: &str = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+    /// Mocks a vault at `native_vault` with a native base token, a vault at
+    /// `cw20_vault` with the cw20 base token `cw20_token`, and a vault at
+    /// `broken_vault` whose base token `not_a_token` fails all queries.
+    #[cfg(feature = "cw20")]
+    fn mock_querier() -> MockQuerier {
+        let mut querier = MockQuerier::new(&[]);
+        querier.update_wasm(|query| {
+            let WasmQuery::Smart { contract_addr, msg } = query else {
+                panic!("unexpected query: {query:?}");
+            };
+            let res = match contract_addr.as_str() {
+                "native_vault" | "cw20_vault" | "broken_vault" => {
+                    let base_token = match contract_addr.as_str() {
+                        "native_vault" => IBC_DENOM,
+                        "cw20_vault" => "cw20_token",
+                        _ => "not_a_token",
+                    };
+                    match from_json(msg).unwrap() {
+                        VaultStandardQueryMsg::<ExtensionQueryMsg>::Info {} => {
+                            to_json_binary(&VaultInfoResponse {
+                                base_token: base_token.to_string(),
+                                vault_token: format!("factory/{contract_addr}/vault"),
+                            })
+                        }
+                        msg => panic!("unexpected query: {msg:?}"),
+                    }
+                }
+                "cw20_token" => match from_json(msg).unwrap() {
+                    Cw20QueryMsg::TokenInfo {} => to_json_binary(&TokenInfoResponse {
+                        name: "Token".to_string(),
+                        symbol: "TKN".to_string(),
+                        decimals: 6,
+                        total_supply: Uint128::new(1000),
+                    }),
+                    msg => panic!("unexpected query: {msg:?}"),
+                },
+                _ => {
+                    return SystemResult::Ok(ContractResult::Err("query not supported".to_string()))
+                }
+            };
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        });
+        querier
+    }
+
+    #[test]
+    fn try_from_str_validates_address() {
+        let api = MockApi::default();
+
+        let vault: VaultContract = VaultContract::try_from(("vault", &api as &dyn Api)).unwrap();
+        assert_eq!(vault.addr, Addr::unchecked("vault"));
+
+        let res: StdResult<VaultContract> = VaultContract::try_from(("Vault", &api as &dyn Api));
+        assert!(res.is_err());
+    }
+
+    #[cfg(feature = "cw20")]
+    #[test]
+    fn deposit_any_attaches_native_base_token() {
+        let api = MockApi::default();
+        let mock = mock_querier();
+        let querier = QuerierWrapper::<Empty>::new(&mock);
+        let vault: VaultContract = VaultContract::new(&Addr::unchecked("native_vault"));
+
+        let msgs: Vec<CosmosMsg> = vault
+            .deposit_any(&api, &querier, 100u128, None, Cw20DepositMethod::SendHook)
+            .unwrap();
+        assert_eq!(msgs, vec![vault.deposit(100u128, IBC_DENOM, None).unwrap()]);
+    }
+
+    #[cfg(feature = "cw20")]
+    #[test]
+    fn deposit_any_deposits_cw20_base_token() {
+        let api = MockApi::default();
+        let mock = mock_querier();
+        let querier = QuerierWrapper::<Empty>::new(&mock);
+        let vault: VaultContract = VaultContract::new(&Addr::unchecked("cw20_vault"));
+
+        let msgs: Vec<CosmosMsg> = vault
+            .deposit_any(&api, &querier, 100u128, None, Cw20DepositMethod::SendHook)
+            .unwrap();
+        assert_eq!(
+            msgs,
+            vec![vault
+                .deposit_cw20_via_hook("cw20_token", 100u128, None)
+                .unwrap()]
+        );
+
+        let msgs: Vec<CosmosMsg> = vault
+            .deposit_any(&api, &querier, 100u128, None, Cw20DepositMethod::Allowance)
+            .unwrap();
+        assert_eq!(msgs.len(), 2);
+        assert_eq!(
+            msgs[1],
+            vault.deposit_cw20(Uint128::new(100), None).unwrap()
+        );
+    }
+
+    #[cfg(feature = "cw20")]
+    #[test]
+    fn deposit_any_propagates_token_info_error_for_address() {
+        let api = MockApi::default();
+        let mock = mock_querier();
+        let querier = QuerierWrapper::<Empty>::new(&mock);
+        let vault: VaultContract = VaultContract::new(&Addr::unchecked("broken_vault"));
+
+        let res: Result<Vec<CosmosMsg>, _> =
+            vault.deposit_any(&api, &querier, 100u128, None, Cw20DepositMethod::SendHook);
+        assert!(matches!(res, Err(VaultStandardError::Std(_))));
+    }
+
+    /// Mocks a vault at `batch_vault` implementing the BatchPreview extension
+    /// and a vault at `plain_vault` that does not. Both preview two vault
+    /// tokens per base token.
+    #[cfg(feature = "batch-preview")]
+    fn mock_batch_preview_querier() -> MockQuerier {
+        let mut querier = MockQuerier::new(&[]);
+        querier.update_wasm(|query| {
+            let WasmQuery::Smart { contract_addr, msg } = query else {
+                panic!("unexpected query: {query:?}");
+            };
+            let batch = contract_addr == "batch_vault";
+            let res = match from_json(msg).unwrap() {
+                VaultStandardQueryMsg::<ExtensionQueryMsg>::VaultStandardInfo {} => {
+                    to_json_binary(&VaultStandardInfoResponse {
+                        version: 1,
+                        extensions: if batch {
+                            vec![BATCH_PREVIEW_EXTENSION_NAME.to_string()]
+                        } else {
+                            vec![]
+                        },
+                    })
+                }
+                VaultStandardQueryMsg::PreviewDeposit { amount } if !batch => {
+                    to_json_binary(&(amount * Uint128::new(2)))
+                }
+                VaultStandardQueryMsg::PreviewRedeem { amount } if !batch => {
+                    to_json_binary(&(amount / Uint128::new(2)))
+                }
+                VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::BatchPreview(msg))
+                    if batch =>
+                {
+                    let results: Vec<Uint128> = match msg {
+                        BatchPreviewQueryMsg::PreviewDepositBatch { amounts } => {
+                            amounts.into_iter().map(|a| a * Uint128::new(2)).collect()
+                        }
+                        BatchPreviewQueryMsg::PreviewRedeemBatch { amounts } => {
+                            amounts.into_iter().map(|a| a / Uint128::new(2)).collect()
+                        }
+                    };
+                    to_json_binary(&results)
+                }
+                msg => panic!("unexpected query to {contract_addr}: {msg:?}"),
+            };
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        });
+        querier
+    }
+
+    #[cfg(feature = "batch-preview")]
+    #[test]
+    fn batch_previews_match_input_order() {
+        let mock = mock_batch_preview_querier();
+        let querier = QuerierWrapper::<Empty>::new(&mock);
+        let amounts = [30u128, 10, 20].map(Uint128::new);
+
+        for addr in ["batch_vault", "plain_vault"] {
+            let vault: VaultContract = VaultContract::new(&Addr::unchecked(addr));
+            assert_eq!(
+                vault
+                    .query_preview_deposit_batch(&querier, &amounts)
+                    .unwrap(),
+                [60u128, 20, 40].map(Uint128::new),
+                "{addr}"
+            );
+            assert_eq!(
+                vault
+                    .query_preview_redeem_batch(&querier, &amounts)
+                    .unwrap(),
+                [15u128, 5, 10].map(Uint128::new),
+                "{addr}"
+            );
+        }
+    }
 }
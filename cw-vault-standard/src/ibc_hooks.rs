@@ -0,0 +1,118 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, to_json_string, StdError, StdResult, Uint128};
+use serde::Serialize;
+
+use crate::{ExecuteExtension, ExtensionExecuteMsg, VaultContract, VaultStandardExecuteMsg};
+
+/// The memo of an ICS-20 transfer that triggers a contract execution on the
+/// receiving chain through the ibc-hooks middleware.
+#[cw_serde]
+pub struct IbcHooksMemo<T = ExtensionExecuteMsg> {
+    /// The contract execution to trigger.
+    pub wasm: WasmHook<T>,
+}
+
+/// The contract execution in an [`IbcHooksMemo`].
+#[cw_serde]
+pub struct WasmHook<T = ExtensionExecuteMsg> {
+    /// The address of the contract to execute.
+    pub contract: String,
+    /// The execute message to send to the contract, with the transferred
+    /// tokens as funds.
+    pub msg: VaultStandardExecuteMsg<T>,
+}
+
+impl<T> IbcHooksMemo<T>
+where
+    T: Serialize,
+{
+    /// Parses an ibc-hooks memo from the memo string of an ICS-20 transfer.
+    pub fn from_memo(memo: &str) -> StdResult<Self>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        from_json(memo.as_bytes())
+    }
+
+    /// Returns the JSON memo string to attach to an ICS-20 transfer.
+    pub fn to_memo(&self) -> StdResult<String> {
+        to_json_string(self)
+    }
+
+    /// Returns the deposited amount and the recipient of the vault tokens if
+    /// the memo is a `Deposit` into the vault with address `vault`. Returns an
+    /// error if the memo targets another contract or contains another message.
+    ///
+    /// The recipient is required, since the sender of a contract execution
+    /// triggered through ibc-hooks is an intermediate address derived from the
+    /// channel and the original sender, which nobody controls.
+    pub fn into_deposit(self, vault: &str) -> StdResult<(Uint128, String)> {
+        if self.wasm.contract != vault {
+            return Err(StdError::generic_err(format!(
+                "ibc-hooks memo targets contract {}, expected {}",
+                self.wasm.contract, vault
+            )));
+        }
+        match self.wasm.msg {
+            VaultStandardExecuteMsg::Deposit {
+                amount,
+                recipient: Some(recipient),
+            } => Ok((amount, recipient)),
+            VaultStandardExecuteMsg::Deposit {
+                recipient: None, ..
+            } => Err(StdError::generic_err(
+                "ibc-hooks deposit memo must set a recipient",
+            )),
+            _ => Err(StdError::generic_err(
+                "ibc-hooks memo does not contain a Deposit message",
+            )),
+        }
+    }
+}
+
+impl<E, Q> VaultContract<E, Q> {
+    /// Returns the ibc-hooks memo to attach to an ICS-20 transfer of `amount`
+    /// base tokens to this vault, so that the tokens are deposited into the
+    /// vault in the same packet and the vault tokens are sent to `recipient`
+    /// on the vault chain.
+    ///
+    /// `amount` must equal the amount of the transfer, and the base token of
+    /// the vault must be the IBC denom of the transferred tokens on the vault
+    /// chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cosmwasm_std::{Addr, Uint128};
+    /// use cw_vault_standard::ibc_hooks::IbcHooksMemo;
+    /// use cw_vault_standard::VaultContract;
+    ///
+    /// let vault: VaultContract = VaultContract::new(&Addr::unchecked("vault"));
+    /// let memo = vault.ibc_hooks_deposit_memo(100u128, "recipient").unwrap();
+    ///
+    /// let deposit = IbcHooksMemo::<cw_vault_standard::ExtensionExecuteMsg>::from_memo(&memo)
+    ///     .unwrap()
+    ///     .into_deposit("vault")
+    ///     .unwrap();
+    /// assert_eq!(deposit, (Uint128::new(100), "recipient".to_string()));
+    /// ```
+    pub fn ibc_hooks_deposit_memo(
+        &self,
+        amount: impl Into<Uint128>,
+        recipient: impl Into<String>,
+    ) -> StdResult<String>
+    where
+        E: ExecuteExtension,
+    {
+        IbcHooksMemo {
+            wasm: WasmHook {
+                contract: self.addr.to_string(),
+                msg: VaultStandardExecuteMsg::<E>::Deposit {
+                    amount: amount.into(),
+                    recipient: Some(recipient.into()),
+                },
+            },
+        }
+        .to_memo()
+    }
+}
@@ -0,0 +1,58 @@
+use cosmwasm_std::{
+    instantiate2_address, to_json_binary, Addr, Api, Binary, Coin, CosmosMsg, QuerierWrapper,
+    StdError, StdResult, WasmMsg,
+};
+use serde::Serialize;
+
+use crate::{ExecuteExtension, QueryExtension, VaultContract};
+
+/// Computes the address of a vault instantiated with `Instantiate2` from the
+/// code with id `code_id` by `creator` with the given `salt`, before it is
+/// created. The checksum of the code is queried from the chain.
+pub fn predict_vault_address(
+    api: &dyn Api,
+    querier: &QuerierWrapper,
+    code_id: u64,
+    creator: &Addr,
+    salt: &Binary,
+) -> StdResult<Addr> {
+    let checksum = querier.query_wasm_code_info(code_id)?.checksum;
+    let creator = api.addr_canonicalize(creator.as_str())?;
+    let addr = instantiate2_address(&checksum, &creator, salt)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    api.addr_humanize(&addr)
+}
+
+/// Returns a `WasmMsg::Instantiate2` CosmosMsg instantiating a vault from the
+/// code with id `code_id` with the instantiate message `msg`, together with a
+/// [`VaultContract`] for the predicted address of the vault, see
+/// [`predict_vault_address`]. `creator` must be the address of the contract
+/// sending the message, e.g. a vault factory.
+#[allow(clippy::too_many_arguments)]
+pub fn instantiate2_vault<E, Q, C, M>(
+    api: &dyn Api,
+    querier: &QuerierWrapper,
+    creator: &Addr,
+    admin: Option<String>,
+    code_id: u64,
+    label: impl Into<String>,
+    msg: &M,
+    funds: Vec<Coin>,
+    salt: Binary,
+) -> StdResult<(CosmosMsg<C>, VaultContract<E, Q>)>
+where
+    E: ExecuteExtension,
+    Q: QueryExtension,
+    M: Serialize,
+{
+    let addr = predict_vault_address(api, querier, code_id, creator, &salt)?;
+    let instantiate = WasmMsg::Instantiate2 {
+        admin,
+        code_id,
+        label: label.into(),
+        msg: to_json_binary(msg)?,
+        funds,
+        salt,
+    };
+    Ok((instantiate.into(), VaultContract::new(&addr)))
+}
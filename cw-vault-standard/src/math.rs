@@ -0,0 +1,246 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{StdError, StdResult, Uint128, Uint256};
+
+/// The direction in which to round the result of a conversion between base
+/// tokens and vault tokens.
+///
+/// Conversions should always round in favor of the vault, so that no user can
+/// extract value from the other vault token holders through rounding. I.e. the
+/// vault tokens minted on a deposit and the base tokens returned on a redeem
+/// should be rounded [`Rounding::Down`], while the vault tokens required to
+/// withdraw a given amount of base tokens, or the base tokens required to mint
+/// a given amount of vault tokens, should be rounded [`Rounding::Up`].
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Rounding {
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+}
+
+/// Returns `x * numerator / denominator` rounded in the given direction. The
+/// intermediate product is computed as a [`Uint256`], so the calculation only
+/// fails if the denominator is zero or the result does not fit in a
+/// [`Uint128`].
+pub fn mul_div(
+    x: Uint128,
+    numerator: Uint128,
+    denominator: Uint128,
+    rounding: Rounding,
+) -> StdResult<Uint128> {
+    let product = x.full_mul(numerator);
+    let denominator = Uint256::from(denominator);
+    let result = product.checked_div(denominator)?;
+    let result = match rounding {
+        Rounding::Up if !product.checked_rem(denominator)?.is_zero() => result + Uint256::one(),
+        _ => result,
+    };
+    Ok(Uint128::try_from(result)?)
+}
+
+/// Converts an amount of base tokens to vault tokens, given the total amount
+/// of base tokens held by the vault and the total supply of vault tokens.
+///
+/// If no vault tokens have been minted yet, base tokens are converted 1:1.
+/// Returns an error if vault tokens have been minted but the vault holds no
+/// base tokens, since the vault tokens are then worthless and no meaningful
+/// conversion rate exists.
+///
+/// # Examples
+///
+/// ```
+/// use cosmwasm_std::Uint128;
+/// use cw_vault_standard::math::{convert_to_shares, Rounding};
+///
+/// let (total_assets, total_supply) = (Uint128::new(3), Uint128::new(10));
+///
+/// // 1 * 10 / 3 = 3.33...
+/// let down = convert_to_shares(Uint128::one(), total_assets, total_supply, Rounding::Down);
+/// let up = convert_to_shares(Uint128::one(), total_assets, total_supply, Rounding::Up);
+/// assert_eq!(down.unwrap(), Uint128::new(3));
+/// assert_eq!(up.unwrap(), Uint128::new(4));
+///
+/// // Intermediate products larger than `Uint128::MAX` do not overflow
+/// let shares = convert_to_shares(Uint128::MAX, Uint128::MAX, Uint128::MAX, Rounding::Down);
+/// assert_eq!(shares.unwrap(), Uint128::MAX);
+/// ```
+pub fn convert_to_shares(
+    assets: Uint128,
+    total_assets: Uint128,
+    total_supply: Uint128,
+    rounding: Rounding,
+) -> StdResult<Uint128> {
+    if total_supply.is_zero() {
+        return Ok(assets);
+    }
+    if total_assets.is_zero() {
+        return Err(StdError::generic_err(
+            "Cannot convert to vault tokens: vault tokens exist but the vault holds no assets",
+        ));
+    }
+    mul_div(assets, total_supply, total_assets, rounding)
+}
+
+/// Converts an amount of vault tokens to base tokens, given the total amount
+/// of base tokens held by the vault and the total supply of vault tokens. This
+/// is the inverse of [`convert_to_shares`].
+///
+/// If no vault tokens have been minted yet, vault tokens are converted 1:1.
+///
+/// # Examples
+///
+/// ```
+/// use cosmwasm_std::Uint128;
+/// use cw_vault_standard::math::{convert_to_assets, convert_to_shares, Rounding};
+///
+/// let (total_assets, total_supply) = (Uint128::new(10), Uint128::new(3));
+///
+/// // 1 * 10 / 3 = 3.33...
+/// let down = convert_to_assets(Uint128::one(), total_assets, total_supply, Rounding::Down);
+/// let up = convert_to_assets(Uint128::one(), total_assets, total_supply, Rounding::Up);
+/// assert_eq!(down.unwrap(), Uint128::new(3));
+/// assert_eq!(up.unwrap(), Uint128::new(4));
+///
+/// // Rounding in favor of the vault never returns more assets than deposited
+/// let assets = Uint128::new(7);
+/// let shares = convert_to_shares(assets, total_assets, total_supply, Rounding::Down).unwrap();
+/// let redeemed = convert_to_assets(shares, total_assets, total_supply, Rounding::Down).unwrap();
+/// assert!(redeemed <= assets);
+/// ```
+pub fn convert_to_assets(
+    shares: Uint128,
+    total_assets: Uint128,
+    total_supply: Uint128,
+    rounding: Rounding,
+) -> StdResult<Uint128> {
+    if total_supply.is_zero() {
+        return Ok(shares);
+    }
+    mul_div(shares, total_assets, total_supply, rounding)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn uint128() -> impl Strategy<Value = Uint128> {
+        any::<u128>().prop_map(Uint128::new)
+    }
+
+    /// Reference implementation of `x * numerator / denominator` on `u128`,
+    /// for inputs small enough that the product cannot overflow.
+    fn reference_mul_div(x: u64, numerator: u64, denominator: u64, rounding: Rounding) -> u128 {
+        let product = x as u128 * numerator as u128;
+        let denominator = denominator as u128;
+        match rounding {
+            Rounding::Down => product / denominator,
+            Rounding::Up => product.div_ceil(denominator),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn mul_div_matches_reference(
+            x in any::<u64>(),
+            numerator in any::<u64>(),
+            denominator in 1..=u64::MAX,
+        ) {
+            for rounding in [Rounding::Down, Rounding::Up] {
+                let result = mul_div(x.into(), numerator.into(), denominator.into(), rounding);
+                prop_assert_eq!(
+                    result.unwrap().u128(),
+                    reference_mul_div(x, numerator, denominator, rounding)
+                );
+            }
+        }
+
+        #[test]
+        fn mul_div_fails_only_on_overflow(
+            x in uint128(),
+            numerator in uint128(),
+            denominator in uint128().prop_filter("non-zero", |d| !d.is_zero()),
+        ) {
+            let exact = x.full_mul(numerator) / Uint256::from(denominator);
+            let result = mul_div(x, numerator, denominator, Rounding::Down);
+            prop_assert_eq!(result.is_ok(), exact <= Uint256::from(Uint128::MAX));
+            if let Ok(result) = result {
+                prop_assert_eq!(Uint256::from(result), exact);
+            }
+        }
+
+        #[test]
+        fn mul_div_up_is_at_most_one_above_down(
+            x in uint128(),
+            numerator in uint128(),
+            denominator in uint128().prop_filter("non-zero", |d| !d.is_zero()),
+        ) {
+            let down = mul_div(x, numerator, denominator, Rounding::Down);
+            let up = mul_div(x, numerator, denominator, Rounding::Up);
+            if let (Ok(down), Ok(up)) = (down, up) {
+                prop_assert!(up >= down);
+                prop_assert!(up - down <= Uint128::one());
+            }
+        }
+
+        #[test]
+        fn round_trip_does_not_create_assets(
+            assets in uint128(),
+            total_assets in uint128().prop_filter("non-zero", |a| !a.is_zero()),
+            total_supply in uint128(),
+        ) {
+            if let Ok(shares) = convert_to_shares(assets, total_assets, total_supply, Rounding::Down) {
+                let redeemed =
+                    convert_to_assets(shares, total_assets, total_supply, Rounding::Down).unwrap();
+                prop_assert!(redeemed <= assets);
+            }
+        }
+
+        #[test]
+        fn conversions_up_are_at_most_one_above_down(
+            amount in uint128(),
+            total_assets in uint128().prop_filter("non-zero", |a| !a.is_zero()),
+            total_supply in uint128(),
+        ) {
+            for convert in [convert_to_shares, convert_to_assets] {
+                let down = convert(amount, total_assets, total_supply, Rounding::Down);
+                let up = convert(amount, total_assets, total_supply, Rounding::Up);
+                if let (Ok(down), Ok(up)) = (down, up) {
+                    prop_assert!(up >= down);
+                    prop_assert!(up - down <= Uint128::one());
+                }
+            }
+        }
+
+        #[test]
+        fn conversions_are_one_to_one_at_zero_supply(
+            amount in uint128(),
+            total_assets in uint128(),
+        ) {
+            for rounding in [Rounding::Down, Rounding::Up] {
+                prop_assert_eq!(
+                    convert_to_shares(amount, total_assets, Uint128::zero(), rounding).unwrap(),
+                    amount
+                );
+                prop_assert_eq!(
+                    convert_to_assets(amount, total_assets, Uint128::zero(), rounding).unwrap(),
+                    amount
+                );
+            }
+        }
+
+        #[test]
+        fn convert_to_shares_fails_without_assets(
+            amount in uint128(),
+            total_supply in uint128().prop_filter("non-zero", |s| !s.is_zero()),
+        ) {
+            for rounding in [Rounding::Down, Rounding::Up] {
+                prop_assert!(
+                    convert_to_shares(amount, Uint128::zero(), total_supply, rounding).is_err()
+                );
+            }
+        }
+    }
+}
@@ -1,13 +1,110 @@
+#[cfg(feature = "admin")]
+use crate::extensions::admin::{AdminExecuteMsg, AdminQueryMsg};
+#[cfg(feature = "allowlist")]
+use crate::extensions::allowlist::{AllowlistExecuteMsg, AllowlistQueryMsg};
+#[cfg(feature = "async-redeem")]
+use crate::extensions::async_redeem::{AsyncRedeemExecuteMsg, AsyncRedeemQueryMsg};
+#[cfg(feature = "attestation")]
+use crate::extensions::attestation::{AttestationExecuteMsg, AttestationQueryMsg};
+#[cfg(feature = "batch-deposit")]
+use crate::extensions::batch_deposit::{BatchDepositExecuteMsg, BatchDepositQueryMsg};
+#[cfg(feature = "batch-preview")]
+use crate::extensions::batch_preview::BatchPreviewQueryMsg;
+#[cfg(feature = "blocklist")]
+use crate::extensions::blocklist::{BlocklistExecuteMsg, BlocklistQueryMsg};
+#[cfg(feature = "boost")]
+use crate::extensions::boost::{BoostExecuteMsg, BoostQueryMsg};
+#[cfg(feature = "child-vaults")]
+use crate::extensions::child_vaults::{ChildVaultsExecuteMsg, ChildVaultsQueryMsg};
+#[cfg(feature = "circuit-breaker")]
+use crate::extensions::circuit_breaker::{CircuitBreakerExecuteMsg, CircuitBreakerQueryMsg};
+#[cfg(feature = "collateral")]
+use crate::extensions::collateral::CollateralQueryMsg;
+#[cfg(feature = "composition")]
+use crate::extensions::composition::CompositionQueryMsg;
+#[cfg(feature = "cooldown")]
+use crate::extensions::cooldown::CooldownQueryMsg;
+#[cfg(feature = "deposit-cap")]
+use crate::extensions::deposit_cap::{DepositCapExecuteMsg, DepositCapQueryMsg};
+#[cfg(feature = "epoch")]
+use crate::extensions::epoch::{EpochExecuteMsg, EpochQueryMsg};
+#[cfg(feature = "fee-split")]
+use crate::extensions::fee_split::{FeeSplitExecuteMsg, FeeSplitQueryMsg};
+#[cfg(feature = "fee-tiers")]
+use crate::extensions::fee_tiers::FeeTiersQueryMsg;
+#[cfg(feature = "fees")]
+use crate::extensions::fees::{FeesExecuteMsg, FeesQueryMsg};
+#[cfg(feature = "flash-loan")]
+use crate::extensions::flash_loan::{FlashLoanExecuteMsg, FlashLoanQueryMsg};
 #[cfg(feature = "force-unlock")]
-use crate::extensions::force_unlock::ForceUnlockExecuteMsg;
+use crate::extensions::force_unlock::{ForceUnlockExecuteMsg, ForceUnlockQueryMsg};
+#[cfg(feature = "hooks")]
+use crate::extensions::hooks::{HooksExecuteMsg, HooksQueryMsg};
+#[cfg(feature = "incentives")]
+use crate::extensions::incentives::{IncentivesExecuteMsg, IncentivesQueryMsg};
+#[cfg(feature = "insurance")]
+use crate::extensions::insurance::{InsuranceExecuteMsg, InsuranceQueryMsg};
+#[cfg(feature = "interest-rate")]
+use crate::extensions::interest_rate::InterestRateQueryMsg;
 #[cfg(feature = "keeper")]
 use crate::extensions::keeper::{KeeperExecuteMsg, KeeperQueryMsg};
+#[cfg(feature = "leverage")]
+use crate::extensions::leverage::{LeverageExecuteMsg, LeverageQueryMsg};
+#[cfg(feature = "locked-profit")]
+use crate::extensions::locked_profit::LockedProfitQueryMsg;
 #[cfg(feature = "lockup")]
 use crate::extensions::lockup::{LockupExecuteMsg, LockupQueryMsg};
+#[cfg(feature = "losses")]
+use crate::extensions::losses::{LossesExecuteMsg, LossesQueryMsg};
+#[cfg(feature = "migration")]
+use crate::extensions::migration::MigrationExecuteMsg;
+#[cfg(feature = "minimums")]
+use crate::extensions::minimums::{MinimumsExecuteMsg, MinimumsQueryMsg};
+#[cfg(feature = "multi-asset")]
+use crate::extensions::multi_asset::{MultiAssetExecuteMsg, MultiAssetQueryMsg};
+#[cfg(feature = "oracle")]
+use crate::extensions::oracle::OracleQueryMsg;
+#[cfg(feature = "pause")]
+use crate::extensions::pause::{PauseExecuteMsg, PauseQueryMsg};
+#[cfg(feature = "performance")]
+use crate::extensions::performance::PerformanceQueryMsg;
+#[cfg(feature = "permit")]
+use crate::extensions::permit::{PermitExecuteMsg, PermitQueryMsg};
+#[cfg(feature = "rate-limit")]
+use crate::extensions::rate_limit::{RateLimitExecuteMsg, RateLimitQueryMsg};
+#[cfg(feature = "referral")]
+use crate::extensions::referral::{ReferralExecuteMsg, ReferralQueryMsg};
+#[cfg(feature = "rewards")]
+use crate::extensions::rewards::{RewardsExecuteMsg, RewardsQueryMsg};
+#[cfg(feature = "router")]
+use crate::extensions::router::{RouterExecuteMsg, RouterQueryMsg};
+#[cfg(feature = "shutdown")]
+use crate::extensions::shutdown::{ShutdownExecuteMsg, ShutdownQueryMsg};
+#[cfg(feature = "skim")]
+use crate::extensions::skim::{SkimExecuteMsg, SkimQueryMsg};
+#[cfg(feature = "snapshot")]
+use crate::extensions::snapshot::SnapshotQueryMsg;
+#[cfg(feature = "split-redeem")]
+use crate::extensions::split_redeem::SplitRedeemExecuteMsg;
+#[cfg(feature = "staking")]
+use crate::extensions::staking::{StakingExecuteMsg, StakingQueryMsg};
+#[cfg(feature = "strategy")]
+use crate::extensions::strategy::{StrategyExecuteMsg, StrategyQueryMsg};
+#[cfg(feature = "sunset")]
+use crate::extensions::sunset::{SunsetExecuteMsg, SunsetQueryMsg};
+#[cfg(feature = "timelock")]
+use crate::extensions::timelock::{TimelockExecuteMsg, TimelockQueryMsg};
+#[cfg(feature = "twap")]
+use crate::extensions::twap::TwapQueryMsg;
+#[cfg(feature = "vesting")]
+use crate::extensions::vesting::{VestingExecuteMsg, VestingQueryMsg};
+#[cfg(feature = "wrapped-position")]
+use crate::extensions::wrapped_position::{WrappedPositionExecuteMsg, WrappedPositionQueryMsg};
+#[cfg(feature = "zap")]
+use crate::extensions::zap::{ZapExecuteMsg, ZapQueryMsg};
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_binary, Coin, CosmosMsg, Empty, StdResult, Uint128, WasmMsg};
-use schemars::JsonSchema;
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, Empty, StdResult, Uint128, WasmMsg};
 
 /// The default ExecuteMsg variants that all vaults must implement.
 /// This enum can be extended with additional variants by defining an extension
@@ -15,7 +112,9 @@ use schemars::JsonSchema;
 #[cw_serde]
 pub enum VaultStandardExecuteMsg<T = ExtensionExecuteMsg> {
     /// Called to deposit into the vault. Native assets are passed in the funds
-    /// parameter.
+    /// parameter. Cw20 base tokens are either transferred by the vault using
+    /// an allowance given by the caller, or sent to the vault with a
+    /// `Cw20ExecuteMsg::Send` whose hook `msg` is this `Deposit` message.
     Deposit {
         /// The amount of base tokens to deposit.
         amount: Uint128,
@@ -48,10 +147,14 @@ pub enum VaultStandardExecuteMsg<T = ExtensionExecuteMsg> {
 
 impl VaultStandardExecuteMsg {
     /// Convert a [`VaultStandardExecuteMsg`] into a [`CosmosMsg`].
-    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+    pub fn into_cosmos_msg<C>(
+        self,
+        contract_addr: String,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg<C>> {
         Ok(WasmMsg::Execute {
             contract_addr,
-            msg: to_binary(&self)?,
+            msg: to_json_binary(&self)?,
             funds,
         }
         .into())
@@ -69,6 +172,82 @@ pub enum ExtensionExecuteMsg {
     Lockup(LockupExecuteMsg),
     #[cfg(feature = "force-unlock")]
     ForceUnlock(ForceUnlockExecuteMsg),
+    #[cfg(feature = "fees")]
+    Fees(FeesExecuteMsg),
+    #[cfg(feature = "rewards")]
+    Rewards(RewardsExecuteMsg),
+    #[cfg(feature = "pause")]
+    Pause(PauseExecuteMsg),
+    #[cfg(feature = "shutdown")]
+    Shutdown(ShutdownExecuteMsg),
+    #[cfg(feature = "deposit-cap")]
+    DepositCap(DepositCapExecuteMsg),
+    #[cfg(feature = "allowlist")]
+    Allowlist(AllowlistExecuteMsg),
+    #[cfg(feature = "blocklist")]
+    Blocklist(BlocklistExecuteMsg),
+    #[cfg(feature = "multi-asset")]
+    MultiAsset(MultiAssetExecuteMsg),
+    #[cfg(feature = "zap")]
+    Zap(ZapExecuteMsg),
+    #[cfg(feature = "async-redeem")]
+    AsyncRedeem(AsyncRedeemExecuteMsg),
+    #[cfg(feature = "admin")]
+    Admin(AdminExecuteMsg),
+    #[cfg(feature = "timelock")]
+    Timelock(TimelockExecuteMsg),
+    #[cfg(feature = "migration")]
+    Migration(MigrationExecuteMsg),
+    #[cfg(feature = "strategy")]
+    Strategy(StrategyExecuteMsg),
+    #[cfg(feature = "rate-limit")]
+    RateLimit(RateLimitExecuteMsg),
+    #[cfg(feature = "referral")]
+    Referral(ReferralExecuteMsg),
+    #[cfg(feature = "vesting")]
+    Vesting(VestingExecuteMsg),
+    #[cfg(feature = "staking")]
+    Staking(StakingExecuteMsg),
+    #[cfg(feature = "insurance")]
+    Insurance(InsuranceExecuteMsg),
+    #[cfg(feature = "losses")]
+    Losses(LossesExecuteMsg),
+    #[cfg(feature = "flash-loan")]
+    FlashLoan(FlashLoanExecuteMsg),
+    #[cfg(feature = "hooks")]
+    Hooks(HooksExecuteMsg),
+    #[cfg(feature = "router")]
+    Router(RouterExecuteMsg),
+    #[cfg(feature = "leverage")]
+    Leverage(LeverageExecuteMsg),
+    #[cfg(feature = "fee-split")]
+    FeeSplit(FeeSplitExecuteMsg),
+    #[cfg(feature = "epoch")]
+    Epoch(EpochExecuteMsg),
+    #[cfg(feature = "incentives")]
+    Incentives(IncentivesExecuteMsg),
+    #[cfg(feature = "minimums")]
+    Minimums(MinimumsExecuteMsg),
+    #[cfg(feature = "attestation")]
+    Attestation(AttestationExecuteMsg),
+    #[cfg(feature = "split-redeem")]
+    SplitRedeem(SplitRedeemExecuteMsg),
+    #[cfg(feature = "wrapped-position")]
+    WrappedPosition(WrappedPositionExecuteMsg),
+    #[cfg(feature = "permit")]
+    Permit(PermitExecuteMsg),
+    #[cfg(feature = "child-vaults")]
+    ChildVaults(ChildVaultsExecuteMsg),
+    #[cfg(feature = "skim")]
+    Skim(SkimExecuteMsg),
+    #[cfg(feature = "boost")]
+    Boost(BoostExecuteMsg),
+    #[cfg(feature = "batch-deposit")]
+    BatchDeposit(BatchDepositExecuteMsg),
+    #[cfg(feature = "circuit-breaker")]
+    CircuitBreaker(CircuitBreakerExecuteMsg),
+    #[cfg(feature = "sunset")]
+    Sunset(SunsetExecuteMsg),
 }
 
 /// The default QueryMsg variants that all vaults must implement.
@@ -76,10 +255,7 @@ pub enum ExtensionExecuteMsg {
 /// enum and then passing it as the generic argument `T` to this enum.
 #[cw_serde]
 #[derive(QueryResponses)]
-pub enum VaultStandardQueryMsg<T = ExtensionQueryMsg>
-where
-    T: JsonSchema,
-{
+pub enum VaultStandardQueryMsg<T = ExtensionQueryMsg> {
     /// Returns `VaultStandardInfoResponse` with information on the version of
     /// the vault standard used as well as any enabled extensions.
     #[returns(VaultStandardInfoResponse)]
@@ -176,6 +352,102 @@ pub enum ExtensionQueryMsg {
     Keeper(KeeperQueryMsg),
     #[cfg(feature = "lockup")]
     Lockup(LockupQueryMsg),
+    #[cfg(feature = "force-unlock")]
+    ForceUnlock(ForceUnlockQueryMsg),
+    #[cfg(feature = "fees")]
+    Fees(FeesQueryMsg),
+    #[cfg(feature = "rewards")]
+    Rewards(RewardsQueryMsg),
+    #[cfg(feature = "pause")]
+    Pause(PauseQueryMsg),
+    #[cfg(feature = "shutdown")]
+    Shutdown(ShutdownQueryMsg),
+    #[cfg(feature = "deposit-cap")]
+    DepositCap(DepositCapQueryMsg),
+    #[cfg(feature = "allowlist")]
+    Allowlist(AllowlistQueryMsg),
+    #[cfg(feature = "blocklist")]
+    Blocklist(BlocklistQueryMsg),
+    #[cfg(feature = "multi-asset")]
+    MultiAsset(MultiAssetQueryMsg),
+    #[cfg(feature = "zap")]
+    Zap(ZapQueryMsg),
+    #[cfg(feature = "async-redeem")]
+    AsyncRedeem(AsyncRedeemQueryMsg),
+    #[cfg(feature = "twap")]
+    Twap(TwapQueryMsg),
+    #[cfg(feature = "oracle")]
+    Oracle(OracleQueryMsg),
+    #[cfg(feature = "admin")]
+    Admin(AdminQueryMsg),
+    #[cfg(feature = "timelock")]
+    Timelock(TimelockQueryMsg),
+    #[cfg(feature = "strategy")]
+    Strategy(StrategyQueryMsg),
+    #[cfg(feature = "rate-limit")]
+    RateLimit(RateLimitQueryMsg),
+    #[cfg(feature = "referral")]
+    Referral(ReferralQueryMsg),
+    #[cfg(feature = "vesting")]
+    Vesting(VestingQueryMsg),
+    #[cfg(feature = "staking")]
+    Staking(StakingQueryMsg),
+    #[cfg(feature = "insurance")]
+    Insurance(InsuranceQueryMsg),
+    #[cfg(feature = "losses")]
+    Losses(LossesQueryMsg),
+    #[cfg(feature = "snapshot")]
+    Snapshot(SnapshotQueryMsg),
+    #[cfg(feature = "flash-loan")]
+    FlashLoan(FlashLoanQueryMsg),
+    #[cfg(feature = "hooks")]
+    Hooks(HooksQueryMsg),
+    #[cfg(feature = "locked-profit")]
+    LockedProfit(LockedProfitQueryMsg),
+    #[cfg(feature = "router")]
+    Router(RouterQueryMsg),
+    #[cfg(feature = "leverage")]
+    Leverage(LeverageQueryMsg),
+    #[cfg(feature = "fee-split")]
+    FeeSplit(FeeSplitQueryMsg),
+    #[cfg(feature = "performance")]
+    Performance(PerformanceQueryMsg),
+    #[cfg(feature = "epoch")]
+    Epoch(EpochQueryMsg),
+    #[cfg(feature = "incentives")]
+    Incentives(IncentivesQueryMsg),
+    #[cfg(feature = "minimums")]
+    Minimums(MinimumsQueryMsg),
+    #[cfg(feature = "cooldown")]
+    Cooldown(CooldownQueryMsg),
+    #[cfg(feature = "attestation")]
+    Attestation(AttestationQueryMsg),
+    #[cfg(feature = "wrapped-position")]
+    WrappedPosition(WrappedPositionQueryMsg),
+    #[cfg(feature = "permit")]
+    Permit(PermitQueryMsg),
+    #[cfg(feature = "child-vaults")]
+    ChildVaults(ChildVaultsQueryMsg),
+    #[cfg(feature = "skim")]
+    Skim(SkimQueryMsg),
+    #[cfg(feature = "interest-rate")]
+    InterestRate(InterestRateQueryMsg),
+    #[cfg(feature = "collateral")]
+    Collateral(CollateralQueryMsg),
+    #[cfg(feature = "boost")]
+    Boost(BoostQueryMsg),
+    #[cfg(feature = "batch-deposit")]
+    BatchDeposit(BatchDepositQueryMsg),
+    #[cfg(feature = "fee-tiers")]
+    FeeTiers(FeeTiersQueryMsg),
+    #[cfg(feature = "circuit-breaker")]
+    CircuitBreaker(CircuitBreakerQueryMsg),
+    #[cfg(feature = "composition")]
+    Composition(CompositionQueryMsg),
+    #[cfg(feature = "sunset")]
+    Sunset(SunsetQueryMsg),
+    #[cfg(feature = "batch-preview")]
+    BatchPreview(BatchPreviewQueryMsg),
 }
 
 /// Struct returned from QueryMsg::VaultStandardInfo with information about the
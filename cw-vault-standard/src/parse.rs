@@ -0,0 +1,163 @@
+use cosmwasm_std::{from_json, Reply, StdError, StdResult};
+use cw_utils::parse_execute_response_data;
+
+use crate::extensions::lockup::{
+    UNLOCKING_POSITION_ATTR_KEY, UNLOCKING_POSITION_CREATED_EVENT_TYPE,
+};
+
+/// Parses the lockup id of the unlocking position created by a lockup
+/// `Unlock` message from the [`Reply`] of the submessage.
+///
+/// The lockup id is read from the response data of the vault, which should
+/// contain the JSON encoded u64 lockup id. If the vault did not set any
+/// response data, or the data could not be parsed, the lockup id is read from
+/// the `UNLOCKING_POSITION_ATTR_KEY` attribute of the
+/// `UNLOCKING_POSITION_CREATED_EVENT_TYPE` event instead.
+///
+/// # Examples
+///
+/// Parsing the lockup id from the response data:
+///
+/// ```
+/// use cosmwasm_std::{Binary, Reply, SubMsgResponse, SubMsgResult};
+/// use cw_vault_standard::parse::unlock_lockup_id_from_reply;
+///
+/// // The protobuf encoded `MsgExecuteContractResponse` with data `42`
+/// let data = Binary::from(vec![0x0a, 0x02, b'4', b'2']);
+/// let reply = Reply {
+///     id: 1,
+///     result: SubMsgResult::Ok(SubMsgResponse {
+///         events: vec![],
+///         data: Some(data),
+///     }),
+/// };
+/// assert_eq!(unlock_lockup_id_from_reply(&reply).unwrap(), 42);
+/// ```
+///
+/// Falling back to the event attribute:
+///
+/// ```
+/// use cosmwasm_std::{Event, Reply, SubMsgResponse, SubMsgResult};
+/// use cw_vault_standard::parse::unlock_lockup_id_from_reply;
+///
+/// let reply = Reply {
+///     id: 1,
+///     result: SubMsgResult::Ok(SubMsgResponse {
+///         events: vec![
+///             Event::new("wasm-unlocking_position_created").add_attribute("lockup_id", "7")
+///         ],
+///         data: None,
+///     }),
+/// };
+/// assert_eq!(unlock_lockup_id_from_reply(&reply).unwrap(), 7);
+/// ```
+pub fn unlock_lockup_id_from_reply(reply: &Reply) -> StdResult<u64> {
+    let response = reply
+        .result
+        .clone()
+        .into_result()
+        .map_err(StdError::generic_err)?;
+
+    if let Some(lockup_id) = response
+        .data
+        .as_ref()
+        .and_then(|data| parse_execute_response_data(data).ok())
+        .and_then(|res| res.data)
+        .and_then(|data| from_json::<u64>(&data).ok())
+    {
+        return Ok(lockup_id);
+    }
+
+    let event_type = format!("wasm-{UNLOCKING_POSITION_CREATED_EVENT_TYPE}");
+    let value = response
+        .events
+        .iter()
+        .filter(|event| event.ty == event_type)
+        .flat_map(|event| &event.attributes)
+        .find(|attr| attr.key == UNLOCKING_POSITION_ATTR_KEY)
+        .map(|attr| attr.value.as_str())
+        .ok_or_else(|| StdError::generic_err("No lockup id found in reply"))?;
+
+    value
+        .parse::<u64>()
+        .map_err(|e| StdError::parse_err("u64", e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Binary, Event, SubMsgResponse, SubMsgResult};
+
+    use super::*;
+
+    fn reply(result: SubMsgResult) -> Reply {
+        Reply { id: 1, result }
+    }
+
+    fn ok_reply(events: Vec<Event>, data: Option<Binary>) -> Reply {
+        reply(SubMsgResult::Ok(SubMsgResponse { events, data }))
+    }
+
+    fn unlocking_event(lockup_id: &str) -> Event {
+        Event::new(format!("wasm-{UNLOCKING_POSITION_CREATED_EVENT_TYPE}"))
+            .add_attribute(UNLOCKING_POSITION_ATTR_KEY, lockup_id)
+    }
+
+    /// Returns a protobuf encoded `MsgExecuteContractResponse` with `data`.
+    fn execute_response_data(data: &[u8]) -> Binary {
+        let mut encoded = vec![0x0a, data.len() as u8];
+        encoded.extend_from_slice(data);
+        Binary::from(encoded)
+    }
+
+    #[test]
+    fn parses_lockup_id_from_data() {
+        let reply = ok_reply(
+            vec![unlocking_event("7")],
+            Some(execute_response_data(b"42")),
+        );
+        assert_eq!(unlock_lockup_id_from_reply(&reply).unwrap(), 42);
+    }
+
+    #[test]
+    fn falls_back_to_event_attribute() {
+        let reply = ok_reply(vec![unlocking_event("7")], None);
+        assert_eq!(unlock_lockup_id_from_reply(&reply).unwrap(), 7);
+
+        // Data that does not contain a lockup id is ignored.
+        let reply = ok_reply(
+            vec![unlocking_event("7")],
+            Some(execute_response_data(b"\"not a lockup id\"")),
+        );
+        assert_eq!(unlock_lockup_id_from_reply(&reply).unwrap(), 7);
+    }
+
+    #[test]
+    fn fails_without_lockup_id_attribute() {
+        let reply = ok_reply(
+            vec![Event::new("wasm-other").add_attribute(UNLOCKING_POSITION_ATTR_KEY, "7")],
+            None,
+        );
+        assert_eq!(
+            unlock_lockup_id_from_reply(&reply).unwrap_err(),
+            StdError::generic_err("No lockup id found in reply")
+        );
+    }
+
+    #[test]
+    fn fails_on_non_numeric_lockup_id_attribute() {
+        let reply = ok_reply(vec![unlocking_event("seven")], None);
+        assert!(matches!(
+            unlock_lockup_id_from_reply(&reply).unwrap_err(),
+            StdError::ParseErr { target_type, .. } if target_type == "u64"
+        ));
+    }
+
+    #[test]
+    fn fails_on_error_reply() {
+        let reply = reply(SubMsgResult::Err("unlock failed".to_string()));
+        assert_eq!(
+            unlock_lockup_id_from_reply(&reply).unwrap_err(),
+            StdError::generic_err("unlock failed")
+        );
+    }
+}
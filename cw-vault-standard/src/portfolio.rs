@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult, Uint128};
+
+use crate::{
+    CachedVaultQuerier, ExtensionExecuteMsg, ExtensionQueryMsg, QueryExtension, VaultContract,
+};
+
+/// A basket of vault positions, e.g. the collateral of a credit account or
+/// the holdings of an index product, that can be valued in base tokens.
+pub struct VaultPortfolio<E = ExtensionExecuteMsg, Q = ExtensionQueryMsg> {
+    /// The vaults and the amount of vault tokens held in each of them.
+    pub positions: Vec<(VaultContract<E, Q>, Uint128)>,
+}
+
+/// The value of a single position in a [`VaultPortfolio`].
+#[cw_serde]
+pub struct PositionValue {
+    /// The address of the vault.
+    pub vault: Addr,
+    /// The base token of the vault.
+    pub base_token: String,
+    /// The amount of vault tokens held.
+    pub shares: Uint128,
+    /// The value of the vault tokens in base tokens, rounded down.
+    pub value: Uint128,
+}
+
+/// The value of a [`VaultPortfolio`], as returned by [`VaultPortfolio::value`].
+#[cw_serde]
+pub struct PortfolioValue {
+    /// The value of each position, in the order of the portfolio positions.
+    pub positions: Vec<PositionValue>,
+    /// The total value of all positions, per base token.
+    pub totals: BTreeMap<String, Uint128>,
+}
+
+impl<E, Q> VaultPortfolio<E, Q>
+where
+    Q: QueryExtension,
+{
+    /// Create a new VaultPortfolio instance.
+    pub fn new(positions: Vec<(VaultContract<E, Q>, Uint128)>) -> Self {
+        Self { positions }
+    }
+
+    /// Values all positions in base tokens. Each vault is queried for its
+    /// info, total assets and total vault token supply only once, even if it
+    /// appears in several positions, see [`CachedVaultQuerier`]. Positions in
+    /// vaults without any minted vault tokens are valued 1:1, like
+    /// [`VaultContract::value_of_shares`].
+    pub fn value(&self, querier: &QuerierWrapper) -> StdResult<PortfolioValue> {
+        let mut vaults: Vec<(&Addr, String, CachedVaultQuerier<E, Q>)> = vec![];
+        let mut positions = Vec::with_capacity(self.positions.len());
+        let mut totals = BTreeMap::<String, Uint128>::new();
+
+        for (vault, shares) in &self.positions {
+            let idx = match vaults.iter().position(|(addr, ..)| **addr == vault.addr) {
+                Some(idx) => idx,
+                None => {
+                    let base_token = vault.query_vault_info(querier)?.base_token;
+                    vaults.push((
+                        &vault.addr,
+                        base_token,
+                        CachedVaultQuerier::new(vault, *querier),
+                    ));
+                    vaults.len() - 1
+                }
+            };
+            let (_, base_token, cached) = &vaults[idx];
+
+            let value = cached.convert_to_assets(*shares)?;
+            let total = totals.entry(base_token.clone()).or_default();
+            *total = total.checked_add(value)?;
+            positions.push(PositionValue {
+                vault: vault.addr.clone(),
+                base_token: base_token.clone(),
+                shares: *shares,
+                value,
+            });
+        }
+
+        Ok(PortfolioValue { positions, totals })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockQuerier;
+    use cosmwasm_std::{from_json, to_json_binary, ContractResult, Empty, SystemResult, WasmQuery};
+
+    use super::*;
+    use crate::{VaultInfoResponse, VaultStandardQueryMsg};
+
+    /// Mocks a vault at `empty_vault` without any minted vault tokens and a
+    /// vault at `vault` holding 200 base tokens for 100 vault tokens.
+    fn mock_querier() -> MockQuerier {
+        let mut querier = MockQuerier::new(&[]);
+        querier.update_wasm(|query| {
+            let WasmQuery::Smart { contract_addr, msg } = query else {
+                panic!("unexpected query: {query:?}");
+            };
+            let (total_assets, total_supply) = match contract_addr.as_str() {
+                "empty_vault" => (0u128, 0u128),
+                "vault" => (200, 100),
+                _ => panic!("unexpected contract: {contract_addr}"),
+            };
+            let res = match from_json(msg).unwrap() {
+                VaultStandardQueryMsg::<ExtensionQueryMsg>::Info {} => {
+                    to_json_binary(&VaultInfoResponse {
+                        base_token: "uosmo".to_string(),
+                        vault_token: format!("factory/{contract_addr}/vault"),
+                    })
+                }
+                VaultStandardQueryMsg::TotalAssets {} => {
+                    to_json_binary(&Uint128::new(total_assets))
+                }
+                VaultStandardQueryMsg::TotalVaultTokenSupply {} => {
+                    to_json_binary(&Uint128::new(total_supply))
+                }
+                msg => panic!("unexpected query: {msg:?}"),
+            };
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        });
+        querier
+    }
+
+    #[test]
+    fn values_zero_supply_vault_one_to_one() {
+        let mock = mock_querier();
+        let querier = QuerierWrapper::<Empty>::new(&mock);
+        let empty_vault: VaultContract = VaultContract::new(&Addr::unchecked("empty_vault"));
+        let vault: VaultContract = VaultContract::new(&Addr::unchecked("vault"));
+
+        let portfolio = VaultPortfolio::new(vec![
+            (empty_vault.clone(), Uint128::new(50)),
+            (vault, Uint128::new(10)),
+        ]);
+        let value = portfolio.value(&querier).unwrap();
+
+        assert_eq!(
+            value.positions[0].value,
+            empty_vault.value_of_shares(&querier, 50u128).unwrap()
+        );
+        assert_eq!(value.positions[0].value, Uint128::new(50));
+        assert_eq!(value.positions[1].value, Uint128::new(20));
+        assert_eq!(value.totals["uosmo"], Uint128::new(70));
+    }
+}
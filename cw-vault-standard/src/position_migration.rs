@@ -0,0 +1,346 @@
+use cosmwasm_schema::cw_serde;
+#[cfg(not(feature = "cw4626"))]
+use cosmwasm_std::coin;
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, CosmosMsg, QuerierWrapper, Reply, StdError, SubMsg, Uint128, WasmMsg,
+};
+
+use crate::codec::{decode_reply_data, AssetsReturned};
+use crate::helper::validate_msg;
+#[cfg(feature = "cw4626")]
+use crate::token::VaultToken;
+use crate::{
+    ExecuteExtension, QueryExtension, VaultContract, VaultStandardError, VaultStandardExecuteMsg,
+};
+
+/// The state of a position migration between two vaults with the same base
+/// token, created by [`migrate_position`]. It should be stored in contract
+/// state until the reply to the redeem SubMsg is handled with
+/// [`PositionMigration::handle_reply`].
+#[cw_serde]
+pub struct PositionMigration {
+    /// The address of the vault to deposit the redeemed base tokens into.
+    pub to: Addr,
+    /// The base token of both vaults.
+    pub base_denom: String,
+    /// The minimum amount of base tokens the redeem must return.
+    pub min_out: Uint128,
+}
+
+/// Starts migrating `shares` vault tokens from the vault `from` to the vault
+/// `to`. Queries the info of both vaults to check that they have the same
+/// base token and that it is a native token, i.e. not a valid address, and
+/// returns:
+/// - a SubMsg redeeming the vault tokens from `from`, with a reply on success
+///   with the given `reply_id`,
+/// - the [`PositionMigration`] whose [`PositionMigration::handle_reply`]
+///   returns the message depositing the redeemed base tokens into `to`.
+///
+/// The vault `from` must set the [`AssetsReturned`] response data on
+/// `Redeem`. The vault tokens of `to` are minted to the caller.
+///
+/// With the `cw4626` feature enabled, the vault tokens of a `from` vault that
+/// is its own cw20 vault token are burned directly by the vault. Otherwise
+/// such a vault is rejected, as its vault token cannot be attached as funds.
+pub fn migrate_position<E, Q, C>(
+    api: &dyn Api,
+    querier: &QuerierWrapper,
+    from: &VaultContract<E, Q>,
+    to: &VaultContract<E, Q>,
+    shares: impl Into<Uint128>,
+    min_out: impl Into<Uint128>,
+    reply_id: u64,
+) -> Result<(SubMsg<C>, PositionMigration), VaultStandardError>
+where
+    E: ExecuteExtension,
+    Q: QueryExtension,
+{
+    let from_info = from.query_vault_info(querier)?;
+    let to_info = to.query_vault_info(querier)?;
+    if from_info.base_token != to_info.base_token {
+        return Err(VaultStandardError::BaseTokenMismatch {
+            from: from_info.base_token,
+            to: to_info.base_token,
+        });
+    }
+    if api.addr_validate(&from_info.base_token).is_ok() {
+        return Err(VaultStandardError::NonNativeBaseToken {
+            base_token: from_info.base_token,
+        });
+    }
+
+    let amount = shares.into();
+    validate_msg(&from.addr, amount, &None)?;
+    #[cfg(feature = "cw4626")]
+    let funds = VaultToken::from_vault_info(&from.addr, &from_info).attach_to_redeem(amount);
+    #[cfg(not(feature = "cw4626"))]
+    let funds = {
+        if from_info.vault_token == from.addr.as_str() {
+            return Err(VaultStandardError::NonNativeVaultToken {
+                vault: from.addr.clone(),
+            });
+        }
+        vec![coin(amount.u128(), &from_info.vault_token)]
+    };
+    let redeem: CosmosMsg<C> = WasmMsg::Execute {
+        contract_addr: from.addr.to_string(),
+        msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Redeem {
+            amount,
+            recipient: None,
+        })?,
+        funds,
+    }
+    .into();
+    Ok((
+        SubMsg::reply_on_success(redeem, reply_id),
+        PositionMigration {
+            to: to.addr.clone(),
+            base_denom: from_info.base_token,
+            min_out: min_out.into(),
+        },
+    ))
+}
+
+impl PositionMigration {
+    /// Decodes the amount of base tokens returned by the redeem from the reply
+    /// to the SubMsg returned by [`migrate_position`], checks it against
+    /// `min_out` and returns the message depositing them into the new vault.
+    pub fn handle_reply<C>(&self, reply: &Reply) -> Result<CosmosMsg<C>, VaultStandardError> {
+        let AssetsReturned(assets) = decode_reply_data(reply)?;
+        if assets < self.min_out {
+            return Err(StdError::generic_err(format!(
+                "Redeem returned {} base tokens, less than the minimum of {}",
+                assets, self.min_out
+            ))
+            .into());
+        }
+        let vault: VaultContract = VaultContract::new(&self.to);
+        vault.deposit(assets, &self.base_denom, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{MockApi, MockQuerier};
+    use cosmwasm_std::{
+        coin, from_json, Binary, ContractResult, Empty, SubMsgResponse, SubMsgResult, SystemResult,
+        WasmQuery,
+    };
+
+    use super::*;
+    use crate::{ExtensionQueryMsg, VaultInfoResponse, VaultStandardQueryMsg};
+
+    const IBC_DENOM: &str = "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+    /// Mocks vaults at `from_vault` and `to_vault` with the native base token
+    /// `IBC_DENOM`, a vault at `uosmo_vault` with the base token `uosmo`,
+    /// vaults at `cw20_vault` and `other_cw20_vault` with the cw20 base token
+    /// `cw20_token`, and a vault at `cw4626_vault` that is its own vault
+    /// token.
+    fn mock_querier() -> MockQuerier {
+        let mut querier = MockQuerier::new(&[]);
+        querier.update_wasm(|query| {
+            let WasmQuery::Smart { contract_addr, msg } = query else {
+                panic!("unexpected query: {query:?}");
+            };
+            let (base_token, vault_token) = match contract_addr.as_str() {
+                "from_vault" | "to_vault" => (IBC_DENOM, format!("factory/{contract_addr}/vault")),
+                "uosmo_vault" => ("uosmo", format!("factory/{contract_addr}/vault")),
+                "cw20_vault" | "other_cw20_vault" => {
+                    ("cw20_token", format!("factory/{contract_addr}/vault"))
+                }
+                "cw4626_vault" => (IBC_DENOM, contract_addr.clone()),
+                _ => panic!("unexpected contract: {contract_addr}"),
+            };
+            let res = match from_json(msg).unwrap() {
+                VaultStandardQueryMsg::<ExtensionQueryMsg>::Info {} => {
+                    to_json_binary(&VaultInfoResponse {
+                        base_token: base_token.to_string(),
+                        vault_token,
+                    })
+                }
+                msg => panic!("unexpected query: {msg:?}"),
+            };
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        });
+        querier
+    }
+
+    fn vault(addr: &str) -> VaultContract {
+        VaultContract::new(&Addr::unchecked(addr))
+    }
+
+    fn migration() -> PositionMigration {
+        PositionMigration {
+            to: Addr::unchecked("to_vault"),
+            base_denom: IBC_DENOM.to_string(),
+            min_out: Uint128::new(100),
+        }
+    }
+
+    /// Returns a successful reply with the `MsgExecuteContractResponse` data
+    /// wrapping `data`.
+    fn reply(data: Option<&[u8]>) -> Reply {
+        Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: data.map(|data| {
+                    let mut bytes = vec![0x0a, data.len() as u8];
+                    bytes.extend_from_slice(data);
+                    Binary::from(bytes)
+                }),
+            }),
+        }
+    }
+
+    #[test]
+    fn migrate_position_redeems_from_vault() {
+        let api = MockApi::default();
+        let mock = mock_querier();
+        let querier = QuerierWrapper::<Empty>::new(&mock);
+
+        let (sub_msg, migration_state): (SubMsg, _) = migrate_position(
+            &api,
+            &querier,
+            &vault("from_vault"),
+            &vault("to_vault"),
+            50u128,
+            100u128,
+            1,
+        )
+        .unwrap();
+        assert_eq!(
+            sub_msg,
+            SubMsg::reply_on_success(
+                vault("from_vault")
+                    .redeem::<Empty>(50u128, "factory/from_vault/vault", None)
+                    .unwrap(),
+                1
+            )
+        );
+        assert_eq!(migration_state, migration());
+    }
+
+    #[test]
+    fn migrate_position_rejects_base_token_mismatch() {
+        let api = MockApi::default();
+        let mock = mock_querier();
+        let querier = QuerierWrapper::<Empty>::new(&mock);
+
+        let err = migrate_position::<_, _, Empty>(
+            &api,
+            &querier,
+            &vault("from_vault"),
+            &vault("uosmo_vault"),
+            50u128,
+            100u128,
+            1,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            VaultStandardError::BaseTokenMismatch {
+                from: IBC_DENOM.to_string(),
+                to: "uosmo".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn migrate_position_rejects_cw20_base_token() {
+        let api = MockApi::default();
+        let mock = mock_querier();
+        let querier = QuerierWrapper::<Empty>::new(&mock);
+
+        let err = migrate_position::<_, _, Empty>(
+            &api,
+            &querier,
+            &vault("cw20_vault"),
+            &vault("other_cw20_vault"),
+            50u128,
+            100u128,
+            1,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            VaultStandardError::NonNativeBaseToken {
+                base_token: "cw20_token".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn migrate_position_from_cw4626_vault() {
+        let api = MockApi::default();
+        let mock = mock_querier();
+        let querier = QuerierWrapper::<Empty>::new(&mock);
+
+        let res = migrate_position::<_, _, Empty>(
+            &api,
+            &querier,
+            &vault("cw4626_vault"),
+            &vault("to_vault"),
+            50u128,
+            100u128,
+            1,
+        );
+
+        #[cfg(feature = "cw4626")]
+        {
+            let (sub_msg, _) = res.unwrap();
+            let CosmosMsg::Wasm(WasmMsg::Execute { funds, .. }) = sub_msg.msg else {
+                panic!("unexpected message: {:?}", sub_msg.msg);
+            };
+            assert!(funds.is_empty());
+        }
+        #[cfg(not(feature = "cw4626"))]
+        assert_eq!(
+            res.unwrap_err(),
+            VaultStandardError::NonNativeVaultToken {
+                vault: Addr::unchecked("cw4626_vault"),
+            }
+        );
+    }
+
+    #[test]
+    fn handle_reply_deposits_into_new_vault() {
+        let msg: CosmosMsg = migration().handle_reply(&reply(Some(br#""120""#))).unwrap();
+        let CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            funds,
+            ..
+        }) = &msg
+        else {
+            panic!("unexpected message: {msg:?}");
+        };
+        assert_eq!(contract_addr, "to_vault");
+        assert_eq!(funds, &vec![coin(120, IBC_DENOM)]);
+        assert_eq!(
+            msg,
+            vault("to_vault").deposit(120u128, IBC_DENOM, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn handle_reply_rejects_less_than_min_out() {
+        let err = migration()
+            .handle_reply::<Empty>(&reply(Some(br#""99""#)))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Redeem returned 99 base tokens, less than the minimum of 100")
+                .into()
+        );
+    }
+
+    #[test]
+    fn handle_reply_rejects_missing_data() {
+        let err = migration().handle_reply::<Empty>(&reply(None)).unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("Reply contains no response data").into()
+        );
+    }
+}
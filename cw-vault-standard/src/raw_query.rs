@@ -0,0 +1,54 @@
+use cosmwasm_std::{from_json, QuerierWrapper, StdResult, Uint128};
+
+use crate::{QueryExtension, VaultContract};
+
+/// The raw storage key under which vaults following the raw query convention
+/// store their total assets, as a JSON encoded `Uint128`, e.g. with
+/// `cw_storage_plus::Item::<Uint128>::new(TOTAL_ASSETS_KEY)`. The stored value
+/// must always equal the response to the `TotalAssets` query.
+pub const TOTAL_ASSETS_KEY: &str = "vault_total_assets";
+
+/// The raw storage key under which vaults following the raw query convention
+/// store their total vault token supply, as a JSON encoded `Uint128`. The
+/// stored value must always equal the response to the `TotalVaultTokenSupply`
+/// query.
+pub const TOTAL_VAULT_TOKEN_SUPPLY_KEY: &str = "vault_total_vault_token_supply";
+
+impl<E, Q> VaultContract<E, Q> {
+    fn query_raw_or_else(
+        &self,
+        querier: &QuerierWrapper,
+        key: &str,
+        fallback: impl FnOnce() -> StdResult<Uint128>,
+    ) -> StdResult<Uint128> {
+        match querier.query_wasm_raw(&self.addr, key.as_bytes())? {
+            Some(value) => from_json(value),
+            None => fallback(),
+        }
+    }
+
+    /// Reads the total assets held in the vault from the `TOTAL_ASSETS_KEY`
+    /// raw storage key, which costs less gas than a smart query. Falls back to
+    /// the `TotalAssets` smart query if the vault does not store the key.
+    pub fn query_total_assets_raw(&self, querier: &QuerierWrapper) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
+        self.query_raw_or_else(querier, TOTAL_ASSETS_KEY, || {
+            self.query_total_assets(querier)
+        })
+    }
+
+    /// Reads the total vault token supply from the
+    /// `TOTAL_VAULT_TOKEN_SUPPLY_KEY` raw storage key, which costs less gas
+    /// than a smart query. Falls back to the `TotalVaultTokenSupply` smart
+    /// query if the vault does not store the key.
+    pub fn query_total_vault_token_supply_raw(&self, querier: &QuerierWrapper) -> StdResult<Uint128>
+    where
+        Q: QueryExtension,
+    {
+        self.query_raw_or_else(querier, TOTAL_VAULT_TOKEN_SUPPLY_KEY, || {
+            self.query_total_vault_token_supply(querier)
+        })
+    }
+}
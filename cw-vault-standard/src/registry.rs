@@ -0,0 +1,211 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, QuerierWrapper, StdResult, WasmMsg};
+
+/// Type for the event emitted on call to `RegisterVault`.
+pub const VAULT_REGISTERED_EVENT_TYPE: &str = "vault_registered";
+/// Type for the event emitted on call to `DeregisterVault`.
+pub const VAULT_DEREGISTERED_EVENT_TYPE: &str = "vault_deregistered";
+/// Key for the vault address attribute in the "vault registered" and "vault
+/// deregistered" events.
+pub const REGISTRY_VAULT_ATTR_KEY: &str = "vault";
+
+/// The ExecuteMsg of a vault registry contract, which lets routers and
+/// frontends discover vaults that adhere to the vault standard.
+#[cw_serde]
+pub enum RegistryExecuteMsg {
+    /// Registers a vault. The registry must query the `VaultStandardInfo` and
+    /// `Info` of the vault and store them in the [`RegistryEntry`]. Must fail
+    /// if the vault does not respond to these queries or is already
+    /// registered. Who may register vaults is up to the registry.
+    /// Emits an event with type `VAULT_REGISTERED_EVENT_TYPE` with an
+    /// attribute with key `REGISTRY_VAULT_ATTR_KEY`.
+    RegisterVault {
+        /// The address of the vault.
+        vault: String,
+    },
+
+    /// Callable by registry admin, or the vault itself, to remove a vault
+    /// from the registry.
+    /// Emits an event with type `VAULT_DEREGISTERED_EVENT_TYPE` with an
+    /// attribute with key `REGISTRY_VAULT_ATTR_KEY`.
+    DeregisterVault {
+        /// The address of the vault.
+        vault: String,
+    },
+
+    /// Re-queries the `VaultStandardInfo` and `Info` of a registered vault and
+    /// updates its [`RegistryEntry`], e.g. after the vault was migrated to
+    /// support new extensions. Callable by anyone.
+    RefreshVault {
+        /// The address of the vault.
+        vault: String,
+    },
+}
+
+/// The QueryMsg of a vault registry contract.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum RegistryQueryMsg {
+    /// Returns the [`RegistryEntry`] of a registered vault. Must fail if the
+    /// vault is not registered.
+    #[returns(RegistryEntry)]
+    Vault {
+        /// The address of the vault.
+        vault: String,
+    },
+
+    /// Returns a `Vec<RegistryEntry>` containing all registered vaults.
+    #[returns(Vec<RegistryEntry>)]
+    Vaults {
+        /// Return results only after this vault address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns a `Vec<RegistryEntry>` containing the registered vaults with
+    /// the given base token.
+    #[returns(Vec<RegistryEntry>)]
+    VaultsByBaseToken {
+        /// The base token of the vaults.
+        base_token: String,
+        /// Return results only after this vault address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns a `Vec<RegistryEntry>` containing the registered vaults that
+    /// implement the given extension, e.g. `"lockup"`.
+    #[returns(Vec<RegistryEntry>)]
+    VaultsByExtension {
+        /// The name of the extension, as included in the `extensions` field
+        /// of the `VaultStandardInfoResponse`.
+        extension: String,
+        /// Return results only after this vault address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// A vault registered in a vault registry.
+#[cw_serde]
+pub struct RegistryEntry {
+    /// The address of the vault.
+    pub vault: Addr,
+    /// The base token of the vault.
+    pub base_token: String,
+    /// The vault token of the vault.
+    pub vault_token: String,
+    /// The version of the vault standard implemented by the vault.
+    pub version: u16,
+    /// The extensions implemented by the vault.
+    pub extensions: Vec<String>,
+}
+
+/// A helper struct to interact with a vault registry contract.
+#[cw_serde]
+pub struct RegistryContract {
+    /// The address of the registry contract.
+    pub addr: Addr,
+}
+
+impl RegistryContract {
+    /// Create a new RegistryContract instance.
+    pub fn new(addr: &Addr) -> Self {
+        Self { addr: addr.clone() }
+    }
+
+    fn execute<C>(&self, msg: &RegistryExecuteMsg) -> StdResult<CosmosMsg<C>> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr.to_string(),
+            msg: to_json_binary(msg)?,
+            funds: vec![],
+        }
+        .into())
+    }
+
+    /// Returns a CosmosMsg to register a vault.
+    pub fn register_vault<C>(&self, vault: impl Into<String>) -> StdResult<CosmosMsg<C>> {
+        self.execute(&RegistryExecuteMsg::RegisterVault {
+            vault: vault.into(),
+        })
+    }
+
+    /// Returns a CosmosMsg to deregister a vault.
+    pub fn deregister_vault<C>(&self, vault: impl Into<String>) -> StdResult<CosmosMsg<C>> {
+        self.execute(&RegistryExecuteMsg::DeregisterVault {
+            vault: vault.into(),
+        })
+    }
+
+    /// Returns a CosmosMsg to refresh the entry of a registered vault.
+    pub fn refresh_vault<C>(&self, vault: impl Into<String>) -> StdResult<CosmosMsg<C>> {
+        self.execute(&RegistryExecuteMsg::RefreshVault {
+            vault: vault.into(),
+        })
+    }
+
+    /// Queries the registry for the entry of a registered vault.
+    pub fn query_vault(
+        &self,
+        querier: &QuerierWrapper,
+        vault: impl Into<String>,
+    ) -> StdResult<RegistryEntry> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &RegistryQueryMsg::Vault {
+                vault: vault.into(),
+            },
+        )
+    }
+
+    /// Queries the registry for all registered vaults.
+    pub fn query_vaults(
+        &self,
+        querier: &QuerierWrapper,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<RegistryEntry>> {
+        querier.query_wasm_smart(&self.addr, &RegistryQueryMsg::Vaults { start_after, limit })
+    }
+
+    /// Queries the registry for the registered vaults with the given base
+    /// token.
+    pub fn query_vaults_by_base_token(
+        &self,
+        querier: &QuerierWrapper,
+        base_token: impl Into<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<RegistryEntry>> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &RegistryQueryMsg::VaultsByBaseToken {
+                base_token: base_token.into(),
+                start_after,
+                limit,
+            },
+        )
+    }
+
+    /// Queries the registry for the registered vaults that implement the given
+    /// extension.
+    pub fn query_vaults_by_extension(
+        &self,
+        querier: &QuerierWrapper,
+        extension: impl Into<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<RegistryEntry>> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &RegistryQueryMsg::VaultsByExtension {
+                extension: extension.into(),
+                start_after,
+                limit,
+            },
+        )
+    }
+}
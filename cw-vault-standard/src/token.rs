@@ -0,0 +1,88 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coin, to_json_binary, Addr, BankMsg, Coin, CosmosMsg, QuerierWrapper, StdResult, Uint128,
+    WasmMsg,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+
+use crate::VaultInfoResponse;
+
+/// The vault token of a vault, which is either a native token or, for vaults
+/// implementing the Cw4626 extension, a cw20 token managed by the vault
+/// contract itself.
+#[cw_serde]
+pub enum VaultToken {
+    /// A native vault token, e.g. a TokenFactory denom.
+    Native(String),
+    /// A cw20 vault token, with the address of the vault contract.
+    Cw20(Addr),
+}
+
+impl VaultToken {
+    /// Creates a VaultToken from the response of the `Info` query of the vault
+    /// at `vault_addr`. Since Cw4626 vaults are their own vault token, the
+    /// vault token is a cw20 token if it is equal to the vault address and a
+    /// native token otherwise.
+    pub fn from_vault_info(vault_addr: &Addr, info: &VaultInfoResponse) -> Self {
+        if info.vault_token == vault_addr.as_str() {
+            Self::Cw20(vault_addr.clone())
+        } else {
+            Self::Native(info.vault_token.clone())
+        }
+    }
+
+    /// Queries the vault token balance of an address.
+    pub fn balance(
+        &self,
+        querier: &QuerierWrapper,
+        address: impl Into<String>,
+    ) -> StdResult<Uint128> {
+        match self {
+            Self::Native(denom) => Ok(querier.query_balance(address, denom)?.amount),
+            Self::Cw20(contract) => {
+                let res: BalanceResponse = querier.query_wasm_smart(
+                    contract,
+                    &Cw20QueryMsg::Balance {
+                        address: address.into(),
+                    },
+                )?;
+                Ok(res.balance)
+            }
+        }
+    }
+
+    /// Returns a CosmosMsg to transfer vault tokens to `recipient`.
+    pub fn transfer_msg<C>(
+        &self,
+        recipient: impl Into<String>,
+        amount: impl Into<Uint128>,
+    ) -> StdResult<CosmosMsg<C>> {
+        let amount = amount.into();
+        Ok(match self {
+            Self::Native(denom) => BankMsg::Send {
+                to_address: recipient.into(),
+                amount: vec![coin(amount.u128(), denom)],
+            }
+            .into(),
+            Self::Cw20(contract) => WasmMsg::Execute {
+                contract_addr: contract.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.into(),
+                    amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        })
+    }
+
+    /// Returns the funds to attach to a `Redeem` or `Unlock` message for
+    /// `amount` vault tokens. Native vault tokens must be passed in the funds
+    /// parameter, while cw20 vault tokens are burned directly by the vault.
+    pub fn attach_to_redeem(&self, amount: impl Into<Uint128>) -> Vec<Coin> {
+        match self {
+            Self::Native(denom) => vec![coin(amount.into().u128(), denom)],
+            Self::Cw20(_) => vec![],
+        }
+    }
+}
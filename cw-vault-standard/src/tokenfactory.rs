@@ -0,0 +1,83 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::CosmosMsg;
+use osmosis_std::types::cosmos::bank::v1beta1::{DenomUnit, Metadata};
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::{MsgCreateDenom, MsgSetDenomMetadata};
+
+/// Returns the full denom of the tokenfactory token with subdenom `subdenom`
+/// created by `creator`.
+pub fn tokenfactory_denom(creator: &str, subdenom: &str) -> String {
+    format!("factory/{}/{}", creator, subdenom)
+}
+
+/// Returns a `MsgCreateDenom` CosmosMsg creating the vault token with
+/// subdenom `subdenom`. `sender` must be the address of the vault contract.
+pub fn create_denom_msg<C>(sender: impl Into<String>, subdenom: impl Into<String>) -> CosmosMsg<C> {
+    MsgCreateDenom {
+        sender: sender.into(),
+        subdenom: subdenom.into(),
+    }
+    .into()
+}
+
+/// The bank metadata of a tokenfactory vault token.
+#[cw_serde]
+pub struct VaultTokenMetadata {
+    /// The ticker symbol of the vault token, used as the display denom.
+    pub symbol: String,
+    /// A description of the vault token.
+    pub description: String,
+    /// The exponent of the display denom, i.e. the number of decimals of the
+    /// vault token. Must be zero if the vault token has no display unit
+    /// besides the base denom.
+    pub decimals: u32,
+}
+
+impl VaultTokenMetadata {
+    /// Returns the bank [`Metadata`] of the vault token with subdenom
+    /// `subdenom` created by `creator`.
+    pub fn to_metadata(&self, creator: &str, subdenom: &str) -> Metadata {
+        let base = tokenfactory_denom(creator, subdenom);
+        let mut denom_units = vec![DenomUnit {
+            denom: base.clone(),
+            exponent: 0,
+            aliases: vec![],
+        }];
+        let display = if self.decimals > 0 {
+            denom_units.push(DenomUnit {
+                denom: self.symbol.clone(),
+                exponent: self.decimals,
+                aliases: vec![],
+            });
+            self.symbol.clone()
+        } else {
+            base.clone()
+        };
+
+        Metadata {
+            description: self.description.clone(),
+            denom_units,
+            base,
+            display,
+            name: self.symbol.clone(),
+            symbol: self.symbol.clone(),
+            uri: String::new(),
+            uri_hash: String::new(),
+        }
+    }
+
+    /// Returns a `MsgSetDenomMetadata` CosmosMsg setting the metadata of the
+    /// vault token with subdenom `subdenom`. `sender` must be the address of
+    /// the vault contract that created the denom.
+    pub fn set_denom_metadata_msg<C>(
+        &self,
+        sender: impl Into<String>,
+        subdenom: &str,
+    ) -> CosmosMsg<C> {
+        let sender = sender.into();
+        MsgSetDenomMetadata {
+            metadata: Some(self.to_metadata(&sender, subdenom)),
+            sender,
+        }
+        .into()
+    }
+}
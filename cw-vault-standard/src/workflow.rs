@@ -0,0 +1,91 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    Addr, BlockInfo, CosmosMsg, QuerierWrapper, Reply, StdError, StdResult, SubMsg, Uint128,
+};
+
+use crate::error::VaultStandardError;
+use crate::extensions::lockup::LockupExecuteMsg;
+use crate::helper::validate_recipient;
+use crate::parse::unlock_lockup_id_from_reply;
+use crate::VaultContract;
+
+/// A helper for the multi-transaction flow of exiting a vault that implements
+/// the lockup extension: unlocking vault tokens, waiting for the unlocking
+/// position to mature, and withdrawing the base tokens.
+///
+/// The workflow can be stored in contract state between the steps:
+/// 1. Send the SubMsg returned by [`UnlockWorkflow::unlock_submsg`].
+/// 2. In the reply, call [`UnlockWorkflow::handle_reply`] to store the lockup
+///    id of the created unlocking position.
+/// 3. Once [`UnlockWorkflow::is_mature`] returns true, send the message
+///    returned by [`UnlockWorkflow::withdraw_msg`].
+#[cw_serde]
+pub struct UnlockWorkflow {
+    /// The address of the vault contract.
+    pub vault: Addr,
+    /// The id of the unlocking position, once it has been created.
+    pub lockup_id: Option<u64>,
+}
+
+impl UnlockWorkflow {
+    /// Create a new UnlockWorkflow instance for the given vault.
+    pub fn new(vault: &Addr) -> Self {
+        Self {
+            vault: vault.clone(),
+            lockup_id: None,
+        }
+    }
+
+    fn vault(&self) -> VaultContract {
+        VaultContract::new(&self.vault)
+    }
+
+    fn expect_lockup_id(&self) -> StdResult<u64> {
+        self.lockup_id
+            .ok_or_else(|| StdError::generic_err("Unlocking position has not been created yet"))
+    }
+
+    /// Returns a SubMsg to unlock `amount` vault tokens, with a reply on
+    /// success with the given `reply_id`.
+    pub fn unlock_submsg<C>(
+        &self,
+        amount: impl Into<Uint128>,
+        vault_token_denom: &str,
+        reply_id: u64,
+    ) -> Result<SubMsg<C>, VaultStandardError> {
+        self.vault()
+            .unlock_submsg(amount, vault_token_denom, reply_id)
+    }
+
+    /// Parses the lockup id of the created unlocking position from the reply
+    /// to the SubMsg returned by [`Self::unlock_submsg`] and stores it.
+    pub fn handle_reply(&mut self, reply: &Reply) -> StdResult<u64> {
+        let lockup_id = unlock_lockup_id_from_reply(reply)?;
+        self.lockup_id = Some(lockup_id);
+        Ok(lockup_id)
+    }
+
+    /// Queries the vault for whether the unlocking position has finished
+    /// unlocking and can be withdrawn.
+    pub fn is_mature(&self, querier: &QuerierWrapper, block: &BlockInfo) -> StdResult<bool> {
+        let position = self
+            .vault()
+            .query_lockup(querier, self.expect_lockup_id()?)?;
+        Ok(position.release_at.is_expired(block))
+    }
+
+    /// Returns a CosmosMsg to withdraw the base tokens of the unlocking
+    /// position. Should only be sent once [`Self::is_mature`] returns true.
+    /// Returns an error if `recipient` is the vault itself.
+    pub fn withdraw_msg<C>(
+        &self,
+        recipient: Option<String>,
+    ) -> Result<CosmosMsg<C>, VaultStandardError> {
+        validate_recipient(&self.vault, &recipient)?;
+        Ok(LockupExecuteMsg::WithdrawUnlocked {
+            recipient,
+            lockup_id: self.expect_lockup_id()?,
+        }
+        .into_cosmos_msg(self.vault.to_string(), vec![])?)
+    }
+}